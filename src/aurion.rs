@@ -1,64 +1,472 @@
 #![deny(missing_docs)]
 
-use std::cell::RefCell;
-use std::rc::Rc;
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
+use std::time::{Duration, Instant};
 
 use anyhow::{Error, Result};
-use chrono::{DateTime, Utc};
-#[allow(unused_imports)]
-use log::{debug, error, info, trace, warn};
-use reqwest::cookie::Jar;
-use reqwest::header::SET_COOKIE;
-use reqwest::redirect::Policy;
-use reqwest::{Client, ClientBuilder};
-use serde_json::{json, Value, Value::Bool};
-
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use futures_util::{stream, Stream, StreamExt};
+use reqwest::header::{HeaderMap, CONTENT_TYPE, SET_COOKIE};
+use reqwest::Url;
+use secrecy::{ExposeSecret, SecretString};
+use serde_json::{json, Map, Value, Value::Bool};
+use tracing::{debug, error, info, instrument, trace, warn, Span};
+
+use crate::aurion_builder::AurionBuilder;
+use crate::auth_method::AuthMethod;
+use crate::cache::{DetailsCache, ScheduleCache};
+use crate::capabilities::Capabilities;
 use crate::default::{school_end, school_start};
-use crate::event::{Event, RawEvent};
+use crate::document::{Document, ReportCard};
+use crate::error::AurionError;
+use crate::event::{check_dst, Event, EventDetails, EventId, Exam, KindMapping, RawEvent};
+use crate::format::Translations;
 use crate::menu::{Menu, Node};
 use crate::pages::Pages;
-use crate::schedule::ClassGroup;
-use crate::utils::{get_form_id, get_schedule_form_id, get_view_state};
+use crate::profile::Profile;
+use crate::read_only_aurion::ReadOnlyAurion;
+use crate::roster::Student;
+use crate::schedule::{
+    dedup, diff, is_duplicate, ClassGroup, DateRange, ScheduleRequest, Teacher, UserPlanning,
+    UserPlanningSelector,
+};
+use crate::stages::{Internship, InternshipOffer};
+use crate::telemetry;
+use crate::transport::{
+    redact_headers, redact_request, zeroize_form, Cookie, Exchange, Request, RequestHook,
+    RequestTiming, ReqwestTransport, Response, RetryPolicy, Timeouts, Transport,
+};
+use crate::two_factor::TwoFactor;
+use crate::utils::{
+    day_range, get_button_form_id, get_form_id, get_maintenance_end, get_planning_choice_form_id,
+    get_schedule_form_id, get_view_state, is_maintenance_page, is_view_expired,
+    parse_cas_login_form, parse_documents, parse_event_details, parse_exams,
+    parse_internship_offer, parse_internship_offer_details, parse_internship_offers,
+    parse_internships, parse_menu_roots, parse_planning_choices, parse_profile, parse_roster,
+    parse_table, parse_two_factor_form, shift_weeks, today, week_range, Html,
+};
+use crate::watch::Webhook;
+
+/// The maximum number of days of a schedule date range.
+const MAX_SCHEDULE_SPAN_DAYS: i64 = 2 * 366;
+
+/// Check at compile time that a session can be shared between threads.
+const _: () = {
+    fn assert_send_sync<T: Send + Sync>() {}
+    let _ = assert_send_sync::<Aurion<ReqwestTransport>>;
+};
 
 /// The main Aurion struct.
-pub struct Aurion {
+///
+/// The HTTP layer is provided by a [`Transport`], which defaults to
+/// [`ReqwestTransport`].
+///
+/// The session state, such as the menu tree and the JSF view state, is kept
+/// behind locks, so that every fetching method takes `&self` and a logged in
+/// session can be shared between threads, for example behind an `Arc` in a
+/// web service.
+///
+/// Aurion's JSF pages are stateful: the session holds a single current view,
+/// so the steps of two operations can't be interleaved. Each operation
+/// navigating the pages, such as fetching a schedule, therefore holds the
+/// session until it is done, and concurrent calls on a shared session run
+/// one after the other. To fetch in parallel, use one logged in `Aurion`
+/// instance per session.
+pub struct Aurion<T: Transport = ReqwestTransport> {
     pages: Pages,
-    menu: Menu,
-    view_state: Option<String>,
-    form_id: Option<u8>,
+    auth_method: AuthMethod,
+    two_factor: Option<TwoFactor>,
+    menu_ids: (String, String, String),
+    menu: RwLock<Menu>,
+    navigation: tokio::sync::Mutex<()>,
+    view_state: Mutex<Option<String>>,
+    form_id: Mutex<Option<u8>>,
+    schedule_view: Mutex<Option<ScheduleView>>,
     start: DateTime<Utc>,
     end: DateTime<Utc>,
-    client: Client,
+    timezone: Tz,
+    dst_correction: bool,
+    retry_policy: RetryPolicy,
+    timeouts: Timeouts,
+    schedule_chunk_size: Option<Duration>,
+    cache: ScheduleCache,
+    details_cache: DetailsCache,
+    timings: Option<Mutex<Vec<RequestTiming>>>,
+    pin_host: bool,
+    validate_menu_ids: bool,
+    translations: Translations,
+    kind_mapping: KindMapping,
+    hooks: Vec<Box<dyn RequestHook>>,
+    transport: T,
 }
 
 impl Aurion {
     /// Create a new Aurion instance.
-    pub fn new<S: Into<String>, U: Into<String>, G: Into<String>, T: Into<String>>(
+    pub fn new<S: Into<String>, U: Into<String>, G: Into<String>, V: Into<String>>(
+        language_code: u32,
+        schooling_id: S,
+        user_planning_id: U,
+        groups_planning_id: G,
+        service_url: V,
+    ) -> Self {
+        AurionBuilder::new(
+            language_code,
+            schooling_id,
+            user_planning_id,
+            groups_planning_id,
+            service_url,
+        )
+        .build()
+    }
+}
+
+impl<T: Transport> Aurion<T> {
+    /// Create a new Aurion instance sending its requests through the given
+    /// transport.
+    pub fn with_transport<S: Into<String>, U: Into<String>, G: Into<String>, V: Into<String>>(
         language_code: u32,
         schooling_id: S,
         user_planning_id: U,
         groups_planning_id: G,
-        service_url: T,
+        service_url: V,
+        transport: T,
     ) -> Self {
+        AurionBuilder::new(
+            language_code,
+            schooling_id,
+            user_planning_id,
+            groups_planning_id,
+            service_url,
+        )
+        .transport(transport)
+        .build()
+    }
+
+    /// Create a new Aurion instance from the builder configuration.
+    pub(crate) fn from_builder(builder: AurionBuilder<T>) -> Self {
         Self {
-            pages: Pages::new(service_url),
-            menu: Menu::new(
-                language_code,
-                schooling_id,
-                user_planning_id,
-                groups_planning_id,
+            pages: Pages::new(builder.service_url),
+            auth_method: builder.auth_method,
+            two_factor: builder.two_factor,
+            menu_ids: (
+                builder.schooling_id.clone(),
+                builder.user_planning_id.clone(),
+                builder.groups_planning_id.clone(),
             ),
-            view_state: None,
-            form_id: None,
+            menu: RwLock::new(Menu::new(
+                builder.language_code,
+                builder.schooling_id,
+                builder.user_planning_id,
+                builder.groups_planning_id,
+            )),
+            navigation: tokio::sync::Mutex::new(()),
+            view_state: Mutex::new(None),
+            form_id: Mutex::new(None),
+            schedule_view: Mutex::new(None),
             start: school_start(),
             end: school_end(),
-            client: ClientBuilder::new()
-                .cookie_store(true)
-                .redirect(Policy::none())
-                .build()
-                .unwrap(),
+            timezone: builder.timezone,
+            dst_correction: builder.dst_correction,
+            retry_policy: builder.retry_policy,
+            timeouts: builder.timeouts,
+            schedule_chunk_size: builder.schedule_chunk_size,
+            cache: ScheduleCache::new(builder.cache_ttl),
+            details_cache: DetailsCache::new(builder.details_cache_ttl),
+            timings: match builder.record_timings {
+                true => Some(Mutex::new(Vec::new())),
+                false => None,
+            },
+            pin_host: builder.pin_host,
+            validate_menu_ids: builder.validate_menu_ids,
+            translations: builder.translations,
+            kind_mapping: builder.kind_mapping,
+            hooks: builder.hooks,
+            transport: builder.transport,
+        }
+    }
+
+    /// Get the timezone of the school, to convert the event dates with
+    /// [`Event::start_in`] and [`Event::end_in`].
+    ///
+    /// See [`AurionBuilder::timezone`].
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Get the translations of the labels written by Aurion, to give to the
+    /// renderers.
+    ///
+    /// See [`AurionBuilder::translations`].
+    pub fn translations(&self) -> &Translations {
+        &self.translations
+    }
+
+    /// Get the menu tree.
+    ///
+    /// The menu tree is updated by the fetching methods, so the returned
+    /// guard must be dropped before fetching.
+    pub fn menu(&self) -> RwLockReadGuard<'_, Menu> {
+        self.menu.read().unwrap()
+    }
+
+    /// Get the menu tree mutably, for example to add nodes already known
+    /// and skip their discovery.
+    pub fn menu_mut(&mut self) -> &mut Menu {
+        self.menu.get_mut().unwrap()
+    }
+
+    /// Drop the cached schedules and event details, so that the next fetches
+    /// hit the server.
+    ///
+    /// This has no effect when the cache is disabled, see
+    /// [`AurionBuilder::cache_ttl`].
+    pub fn force_refresh(&self) {
+        self.cache.clear();
+        self.details_cache.clear();
+    }
+
+    /// Drop the cached details of the given event, so that the next fetch
+    /// hits the server.
+    ///
+    /// This has no effect when the details cache is disabled, see
+    /// [`AurionBuilder::details_cache_ttl`].
+    pub fn invalidate_event_details(&self, event_id: &EventId) {
+        self.details_cache.invalidate(event_id);
+    }
+
+    /// Take the timings of the requests sent since the last call, in the
+    /// order they were sent.
+    ///
+    /// Taking the timings before and after an operation such as `login` or
+    /// `get_user_schedule` tells how long Aurion took to answer, the rest of
+    /// the operation being spent parsing. Timings are only recorded when
+    /// enabled with [`AurionBuilder::record_timings`], otherwise this is
+    /// always empty.
+    pub fn take_timings(&self) -> Vec<RequestTiming> {
+        match &self.timings {
+            Some(timings) => std::mem::take(&mut *timings.lock().unwrap()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Restrict this session to the fetching APIs.
+    ///
+    /// See [`ReadOnlyAurion`].
+    pub fn into_read_only(self) -> ReadOnlyAurion<T> {
+        ReadOnlyAurion::new(self)
+    }
+
+    /// Send a request through the transport.
+    ///
+    /// When Aurion answers that the view state submitted with the request
+    /// expired, the view state and form id of the session are refreshed from
+    /// the main page and the request is sent again once. The maintenance page
+    /// of Aurion is turned into an [`AurionError::ServerInMaintenance`].
+    ///
+    /// The form of the request, which may hold credentials, is overwritten
+    /// with zeroes once sent.
+    async fn send(&self, mut request: Request) -> Result<Response> {
+        let result = self.send_refreshing(&request).await;
+        zeroize_form(&mut request.form);
+        result
+    }
+
+    /// Send a request through the transport, refreshing the view state of
+    /// the session when it expired, see [`Aurion::send`].
+    async fn send_refreshing(&self, request: &Request) -> Result<Response> {
+        let response = self.send_with_retries(request).await?;
+        self.check_maintenance(&response)?;
+        if !is_view_expired(&response.body) {
+            return Ok(response);
+        }
+
+        // Only the requests submitting the view state of the session can be
+        // fixed by refreshing it
+        let stale_view_state = self.view_state.lock().unwrap().clone();
+        let submitted_view_state = request
+            .form
+            .as_ref()
+            .and_then(|form| form.get("javax.faces.ViewState"))
+            .and_then(Value::as_str);
+        if stale_view_state.is_none() || submitted_view_state != stale_view_state.as_deref() {
+            warn!("View state submitted to {} expired", request.url);
+            return Ok(response);
+        }
+
+        warn!("View state of the session expired, refreshing it");
+        let refreshed = self
+            .send_with_retries(&Request::get(self.pages.service_url()))
+            .await?;
+        self.check_maintenance(&refreshed)?;
+        let text = refreshed.body;
+        let view_state = match get_view_state(&text) {
+            Some(view_state) => view_state,
+            None => {
+                warn!("Failed to refresh the view state, the session might be logged out");
+                return Ok(response);
+            }
+        };
+        let stale_form_id = *self.form_id.lock().unwrap();
+        let form_id = get_form_id(&text).ok().or(stale_form_id);
+        *self.view_state.lock().unwrap() = Some(view_state.clone());
+        *self.form_id.lock().unwrap() = form_id;
+
+        // Submit the refreshed view state and form id instead of the stale
+        // ones
+        let mut request = request.clone();
+        if let Some(form) = request.form.take() {
+            let mut form = match (stale_form_id, form_id) {
+                (Some(stale), Some(fresh)) if stale != fresh => {
+                    let stale = format!("\"form:j_idt{}\"", stale);
+                    let fresh = format!("\"form:j_idt{}\"", fresh);
+                    serde_json::from_str(&form.to_string().replace(&stale, &fresh))?
+                }
+                _ => form,
+            };
+            form["javax.faces.ViewState"] = Value::String(view_state);
+            request.form = Some(form);
+        }
+
+        let result = self.send_with_retries(&request).await;
+        zeroize_form(&mut request.form);
+        let response = result?;
+        self.check_maintenance(&response)?;
+        Ok(response)
+    }
+
+    /// Send a request through the transport.
+    ///
+    /// Idempotent requests are retried according to the retry policy when
+    /// the transport fails, times out or when the server answers with a
+    /// server error. Every attempt sends the same borrowed request, so that
+    /// its owner only has to overwrite its form with zeroes once.
+    async fn send_with_retries(&self, request: &Request) -> Result<Response> {
+        self.check_host(&request.url)?;
+
+        let timeout = request.timeout.or(self.timeouts.request);
+
+        let mut attempt = 1;
+        loop {
+            for hook in &self.hooks {
+                let mut redacted = redact_request(request, hook.include_bodies());
+                hook.before_request(&redacted, attempt);
+                zeroize_form(&mut redacted.form);
+            }
+
+            let sent_at = Instant::now();
+            let result = match timeout {
+                Some(timeout) => {
+                    match tokio::time::timeout(timeout, self.transport.send(request)).await {
+                        Ok(result) => result,
+                        Err(_) => Err(Error::new(AurionError::Timeout {
+                            url: request.url.to_string(),
+                            timeout,
+                        })),
+                    }
+                }
+                None => self.transport.send(request).await,
+            };
+            let duration = sent_at.elapsed();
+
+            let status = result.as_ref().ok().map(|response| response.status);
+            telemetry::record_request(&request.method, &request.url, status, duration);
+            debug!(
+                "{} {} answered in {:?} (attempt {})",
+                request.method, request.url, duration, attempt
+            );
+            let timing = RequestTiming {
+                method: request.method.clone(),
+                url: request.url.clone(),
+                status,
+                attempt,
+                duration,
+            };
+            for hook in &self.hooks {
+                hook.after_response(&Exchange {
+                    timing: timing.clone(),
+                    headers: result
+                        .as_ref()
+                        .ok()
+                        .map(|response| redact_headers(&response.headers)),
+                    body: match hook.include_bodies() {
+                        true => result.as_ref().ok().map(|response| response.body.clone()),
+                        false => None,
+                    },
+                    error: result.as_ref().err().map(|e| e.to_string()),
+                });
+            }
+            if let Some(timings) = &self.timings {
+                timings.lock().unwrap().push(timing);
+            }
+
+            let failed = match &result {
+                // The maintenance page won't go away by retrying right away
+                Ok(response) => {
+                    response.status.is_server_error() && !is_maintenance_page(&response.body)
+                }
+                Err(_) => true,
+            };
+            if !failed || !request.idempotent || attempt >= self.retry_policy.max_attempts {
+                // Refuse to be redirected off the pinned host
+                if let Ok(response) = &result {
+                    if let Some(location) = response.location() {
+                        self.check_host(&request.url.join(location)?)?;
+                    }
+                }
+                return result;
+            }
+
+            let backoff = self.retry_policy.backoff(attempt);
+            match &result {
+                Ok(response) => warn!(
+                    "Request to {} failed with status {}, retrying in {:?}",
+                    request.url, response.status, backoff
+                ),
+                Err(e) => warn!(
+                    "Request to {} failed: {}, retrying in {:?}",
+                    request.url, e, backoff
+                ),
+            }
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Check that the response isn't the maintenance page of Aurion.
+    fn check_maintenance(&self, response: &Response) -> Result<()> {
+        if !is_maintenance_page(&response.body) {
+            return Ok(());
+        }
+
+        let until = get_maintenance_end(response.body.clone(), self.timezone, today(self.timezone));
+        let error = AurionError::ServerInMaintenance { until };
+        error!("{}", error);
+        Err(Error::new(error))
+    }
+
+    /// Check that the given url targets the service host, when it is pinned.
+    fn check_host(&self, url: &Url) -> Result<()> {
+        let service_url = self.pages.service_url();
+        if !self.pin_host || url.origin() == service_url.origin() {
+            return Ok(());
+        }
+
+        // The CAS server is trusted as it was configured explicitly
+        if let AuthMethod::Cas { cas_url } = &self.auth_method {
+            if url.origin() == cas_url.origin() {
+                return Ok(());
+            }
         }
+
+        let error = AurionError::HostMismatch {
+            expected: service_url.origin().ascii_serialization(),
+            found: url.origin().ascii_serialization(),
+        };
+        error!("{}", error);
+        Err(Error::new(error))
     }
 
     /// Create the default payload for Aurion requests.
@@ -69,17 +477,17 @@ impl Aurion {
             "form:sauvegarde": "",
             "form:largeurDivCenter": "",
             "form:j_idt820_focus": "",
-            "form:j_idt820_input": "",
+            "form:j_idt820_input": self.menu.read().unwrap().language_code().to_string(),
             "form:sidebar": "form:sidebar",
             "form:j_idt805:j_idt808_view": "basicDay",
-            "javax.faces.ViewState": self.view_state,
+            "javax.faces.ViewState": self.view_state.lock().unwrap().clone(),
             "form:sidebar_menuid": menu_id.into(),
         })
     }
 
     /// Add the login credentials manually to the client.
     pub fn manual_login<A: Into<String>, V: Into<String>>(
-        &mut self,
+        &self,
         authentication_token: A,
         view_state: V,
         form_id: u8,
@@ -87,27 +495,67 @@ impl Aurion {
         let authentication_token = authentication_token.into();
         let view_state = view_state.into();
 
-        let cookie_store = Jar::default();
-        cookie_store.add_cookie_str(&authentication_token, &self.pages.service_url());
+        self.transport
+            .add_cookie(&authentication_token, &self.pages.service_url());
 
-        self.client = ClientBuilder::new()
-            .redirect(Policy::none())
-            .cookie_store(true)
-            .cookie_provider(Arc::new(cookie_store))
-            .build()
-            .unwrap();
+        *self.view_state.lock().unwrap() = Some(view_state);
+        *self.form_id.lock().unwrap() = Some(form_id);
+    }
 
-        self.view_state = Some(view_state);
-        self.form_id = Some(form_id);
+    /// Get the cookies of the session, for example to share the session
+    /// with other HTTP tooling.
+    ///
+    /// Only the cookies sent to the service url are returned, and none if
+    /// the transport can't list its cookies, see [`Transport::cookies`].
+    pub fn cookies(&self) -> Vec<Cookie> {
+        self.transport.cookies(&self.pages.service_url())
+    }
+
+    /// Add the given cookies to the session, as if they were set by the
+    /// service, for example a `JSESSIONID` extracted from a browser.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::transport::Cookie;
+    /// use aurion_rs::Aurion;
+    ///
+    /// let aurion = Aurion::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// );
+    /// aurion.set_cookies([Cookie::new("JSESSIONID", "0123")]);
+    /// assert_eq!(aurion.cookies(), vec![Cookie::new("JSESSIONID", "0123")]);
+    /// ```
+    pub fn set_cookies<I: IntoIterator<Item = Cookie>>(&self, cookies: I) {
+        let service_url = self.pages.service_url();
+        for cookie in cookies {
+            self.transport.add_cookie(&cookie.to_string(), &service_url);
+        }
     }
 
     /// Login to Aurion with the given credentials and return the authentication
     /// token.
     ///
+    /// The credentials are checked by the configured [`AuthMethod`], see
+    /// [`AurionBuilder::auth_method`]. When a two-factor challenge follows,
+    /// it is answered with the code of the configured [`TwoFactor`], see
+    /// [`AurionBuilder::two_factor`].
+    ///
+    /// The credentials are kept as [`SecretString`]s, and the forms they are
+    /// copied into are overwritten with zeroes once sent, like the one-time
+    /// codes. The password and the codes are never logged nor given to the
+    /// [`RequestHook`]s in clear.
+    ///
     /// # Example
     ///
     /// ```rust
     /// # use aurion_rs::Aurion;
+    /// use secrecy::SecretString;
+    ///
     /// # async fn run() -> Result<(), reqwest::Error> {
     /// #     let mut aurion = Aurion::new(
     /// #         275805,
@@ -117,55 +565,568 @@ impl Aurion {
     /// #         "https://web.isen-ouest.fr/webAurion/",
     /// #     );
     /// aurion.login("username", "password").await;
+    ///
+    /// // Or with a password already kept secret
+    /// let password = SecretString::from(std::env::var("AURION_PASSWORD").unwrap());
+    /// aurion.login("username", password).await;
     /// #     Ok(())
     /// # }
     /// ```
-    pub async fn login<U: Into<String>, P: Into<String>>(
-        &mut self,
+    #[instrument(skip_all)]
+    pub async fn login<U: Into<SecretString>, P: Into<SecretString>>(
+        &self,
         username: U,
         password: P,
     ) -> Result<String> {
+        let (username, password) = (username.into(), password.into());
+        let _navigation = self.navigation.lock().await;
+
+        // Authenticate and get the headers of the response opening the
+        // session
+        let headers = match &self.auth_method {
+            AuthMethod::Form => self.login_with_form(&username, &password).await,
+            AuthMethod::Cas { cas_url } => self.login_with_cas(cas_url, &username, &password).await,
+        };
+        let headers = match headers {
+            Ok(headers) => headers,
+            Err(e) => {
+                telemetry::record_login(false);
+                return Err(e);
+            }
+        };
+
+        // Get the authentication cookie
+        let cookie = match headers.get(SET_COOKIE) {
+            Some(cookie) => std::str::from_utf8(cookie.as_bytes())?.to_string(),
+            None => {
+                telemetry::record_login(false);
+                let error = AurionError::MissingSessionCookie;
+                error!("{}", error);
+                return Err(Error::new(error));
+            }
+        };
+
+        // Send a dummy request to fetch the view state and form id values from
+        // Aurion's main logged page
+        trace!("Fetching view state and form id values.");
+        let mut dummy_response = self
+            .send(Request::get(self.pages.service_url()).timeout(self.timeouts.login))
+            .await?;
+
+        // The two-factor challenge might only be shown once on Aurion
+        if self
+            .answer_two_factor(&self.pages.service_url(), &dummy_response)
+            .await?
+            .is_some()
+        {
+            dummy_response = self
+                .send(Request::get(self.pages.service_url()).timeout(self.timeouts.login))
+                .await?;
+        }
+        trace!("View state and form id values fetched.");
+        let mut dummy_text = dummy_response.body;
+
+        // Set the view state and form id values if found
+        *self.view_state.lock().unwrap() = get_view_state(&dummy_text);
+        *self.form_id.lock().unwrap() = get_form_id(&dummy_text).ok();
+
+        // Switch to the configured language, so that the labels are parsed in
+        // a predictable language
+        if let Some(text) = self.switch_language().await? {
+            dummy_text = text;
+        }
+
+        // Discover the menu ids if they were not given
+        if !self.menu.read().unwrap().has_menu_ids() {
+            self.discover_menu_ids_from(&dummy_text).await?;
+        } else if self.validate_menu_ids {
+            self.validate_menu_ids_from(&dummy_text).await?;
+        }
+
+        telemetry::record_login(true);
+
+        Ok(cookie)
+    }
+
+    /// Logout from Aurion, ending the session server-side, and forget the
+    /// session: its cookies, view state, cached schedules and loaded menu
+    /// tree, whose menu ids are reset to the configured ones.
+    ///
+    /// With [`AuthMethod::Cas`], the SSO session of the CAS server is ended
+    /// as well. The session is forgotten even if the logout requests fail,
+    /// in which case their error is returned. The cookies can only be
+    /// forgotten if the transport lists them, see [`Transport::cookies`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// # async fn run() -> anyhow::Result<()> {
+    /// #     let aurion = Aurion::new(
+    /// #         275805,
+    /// #         "submenu_291906",
+    /// #         "1_3",
+    /// #         "submenu_299102",
+    /// #         "https://web.isen-ouest.fr/webAurion/",
+    /// #     );
+    /// aurion.login("username", "password").await?;
+    /// let schedule = aurion.get_user_schedule(None, None).await?;
+    /// aurion.logout().await?;
+    ///
+    /// // The same instance can then be used by another user
+    /// aurion.login("other", "password").await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip_all)]
+    pub async fn logout(&self) -> Result<()> {
+        let _navigation = self.navigation.lock().await;
+        trace!("Sending logout request.");
+        let mut result = self
+            .send(Request::get(self.pages.logout_url()).timeout(self.timeouts.login))
+            .await
+            .map(|_| ());
+        trace!("Logout request sent.");
+
+        let mut session_urls = vec![self.pages.service_url()];
+        if let AuthMethod::Cas { cas_url } = &self.auth_method {
+            trace!("Sending CAS logout request.");
+            let cas_result = match cas_logout_url(cas_url) {
+                Ok(logout_url) => self
+                    .send(Request::get(logout_url).timeout(self.timeouts.login))
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            };
+            result = result.and(cas_result);
+            session_urls.push(cas_url.clone());
+        }
+
+        // Forget the session even if the server couldn't be reached
+        for url in &session_urls {
+            self.expire_cookies(url);
+        }
+        *self.view_state.lock().unwrap() = None;
+        *self.form_id.lock().unwrap() = None;
+        *self.schedule_view.lock().unwrap() = None;
+        self.force_refresh();
+        let language_code = self.menu.read().unwrap().language_code();
+        let (schooling_id, user_planning_id, groups_planning_id) = self.menu_ids.clone();
+        *self.menu.write().unwrap() = Menu::new(
+            language_code,
+            schooling_id,
+            user_planning_id,
+            groups_planning_id,
+        );
+
+        if let Err(e) = &result {
+            warn!("Failed to logout from the server: {}", e);
+        }
+        result
+    }
+
+    /// Expire the cookies the transport sends to the given url.
+    ///
+    /// As the path and domain the cookies were set for aren't known, they
+    /// are expired for every path prefix of the url, both as host-only and
+    /// domain cookies.
+    fn expire_cookies(&self, url: &Url) {
+        let mut paths = vec!["/".to_string()];
+        let mut prefix = String::new();
+        for segment in url.path().split('/').filter(|segment| !segment.is_empty()) {
+            prefix = format!("{}/{}", prefix, segment);
+            paths.push(prefix.clone());
+            paths.push(format!("{}/", prefix));
+        }
+
+        let domain = url.host_str().unwrap_or_default();
+        for cookie in self.transport.cookies(url) {
+            for path in &paths {
+                let expired = format!("{}=; Path={}; Max-Age=0", cookie.name, path);
+                self.transport.add_cookie(&expired, url);
+                let expired = format!("{}; Domain={}", expired, domain);
+                self.transport.add_cookie(&expired, url);
+            }
+        }
+    }
+
+    /// Authenticate with the built-in login form of Aurion, returning the
+    /// headers of the response opening the session.
+    async fn login_with_form(
+        &self,
+        username: &SecretString,
+        password: &SecretString,
+    ) -> Result<HeaderMap> {
         // Create the payload for the authentication request
         let payload = json!({
-            "username": username.into(),
-            "password": password.into(),
+            "username": username.expose_secret(),
+            "password": password.expose_secret(),
         });
 
         // Send the request
         trace!("Beginning login request.");
         let response = self
-            .client
-            .post(self.pages.login_url())
-            .form(&payload)
-            .send()
+            .send(Request::post(self.pages.login_url(), payload).timeout(self.timeouts.login))
             .await?;
         trace!("Login request sent.");
 
+        // Answer the two-factor challenge shown instead of the redirection,
+        // keeping the session cookie of the login response unless renewed
+        let mut headers = response.headers.clone();
+        if let Some(answer) = self
+            .answer_two_factor(&self.pages.login_url(), &response)
+            .await?
+        {
+            headers.extend(answer.headers);
+        }
+
         // Check if the credentials are correct with the automated redirection
         // by Aurion
         trace!("Checking login response.");
-        let headers = response.headers().clone();
         if !headers.contains_key("location") {
-            let message = format!("Failed to login: username or password might be wrong.");
+            let message = "Failed to login: username or password might be wrong.".to_string();
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
-        // Send a dummy request to fetch the view state and form id values from
-        // Aurion's main logged page
-        trace!("Fetching view state and form id values.");
-        let dummy_response = self.client.get(self.pages.service_url()).send().await?;
-        trace!("View state and form id values fetched.");
-        let dummy_text = dummy_response.text().await?;
+        Ok(headers)
+    }
 
-        // Set the view state and form id values if found
-        self.view_state = get_view_state(&dummy_text);
-        self.form_id = get_form_id(&dummy_text);
+    /// Authenticate through the CAS server at `cas_url`, returning the
+    /// headers of the response opening the session.
+    ///
+    /// The credentials are posted to the login form of the CAS server, which
+    /// redirects to Aurion with a service ticket. Aurion then validates the
+    /// ticket and opens the session.
+    async fn login_with_cas(
+        &self,
+        cas_url: &Url,
+        username: &SecretString,
+        password: &SecretString,
+    ) -> Result<HeaderMap> {
+        // Let Aurion redirect to the CAS server, so that the service to
+        // authenticate is the one it expects
+        trace!("Fetching CAS login page.");
+        let service_url = self.pages.service_url();
+        let response = self
+            .send(Request::get(service_url.clone()).timeout(self.timeouts.login))
+            .await?;
+        let login_url = match response.location() {
+            Some(location) => service_url.join(location)?,
+            None => cas_login_url(cas_url, &service_url)?,
+        };
+        let login_url = match login_url.origin() == cas_url.origin() {
+            true => login_url,
+            false => cas_login_url(cas_url, &service_url)?,
+        };
 
-        // Get the authentication cookie
-        let cookie = headers.get(SET_COOKIE).unwrap();
+        let response = self
+            .send(Request::get(login_url.clone()).timeout(self.timeouts.login))
+            .await?;
+        trace!("CAS login page fetched.");
+
+        // An SSO session might already be open, in which case the CAS server
+        // directly grants a service ticket
+        let ticket_url = match service_ticket_url(&login_url, &response)? {
+            Some(ticket_url) => ticket_url,
+            None => {
+                let form = match parse_cas_login_form(response.body) {
+                    Some(form) => form,
+                    None => {
+                        let message =
+                            format!("Failed to login: CAS login form not found at {}", login_url);
+                        error!("{}", message);
+                        return Err(Error::msg(message));
+                    }
+                };
+
+                let mut payload = Map::new();
+                for (name, value) in form.fields {
+                    payload.insert(name, Value::String(value));
+                }
+                payload.insert("username".to_string(), json!(username.expose_secret()));
+                payload.insert("password".to_string(), json!(password.expose_secret()));
+
+                trace!("Beginning CAS login request.");
+                let action_url = login_url.join(&form.action)?;
+                let response = self
+                    .send(
+                        Request::post(action_url.clone(), Value::Object(payload))
+                            .timeout(self.timeouts.login),
+                    )
+                    .await?;
+                trace!("CAS login request sent.");
+
+                // Answer the two-factor challenge of the CAS server, if any
+                let response = match self.answer_two_factor(&action_url, &response).await? {
+                    Some(answer) => answer,
+                    None => response,
+                };
+
+                // The CAS server only grants a service ticket to valid
+                // credentials
+                match service_ticket_url(&action_url, &response)? {
+                    Some(ticket_url) => ticket_url,
+                    None => {
+                        let message =
+                            "Failed to login: username or password might be wrong.".to_string();
+                        error!("{}", message);
+                        return Err(Error::msg(message));
+                    }
+                }
+            }
+        };
+
+        // Hand the service ticket to Aurion
+        trace!("Validating CAS service ticket.");
+        let response = self
+            .send(Request::get(ticket_url).timeout(self.timeouts.login))
+            .await?;
+        if !response.headers.contains_key(SET_COOKIE) {
+            let message =
+                "Failed to login: the CAS service ticket was refused by Aurion.".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        Ok(response.headers)
+    }
+
+    /// Answer the two-factor challenge shown in the response to `url`, if
+    /// any, with the code of the configured [`TwoFactor`], and return the
+    /// response to the code.
+    async fn answer_two_factor(&self, url: &Url, response: &Response) -> Result<Option<Response>> {
+        let form = match parse_two_factor_form(response.body.clone()) {
+            Some(form) => form,
+            None => return Ok(None),
+        };
+
+        let two_factor = match &self.two_factor {
+            Some(two_factor) => two_factor,
+            None => {
+                let message =
+                    "Failed to login: a two-factor code is required but no two-factor method is configured."
+                        .to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        // Keep the code secret like the password, its form being zeroized
+        // once sent
+        let action_url = url.join(&form.action)?;
+        let code = SecretString::from(two_factor.code().await?);
+        let mut payload = Map::new();
+        for (name, value) in form.fields {
+            payload.insert(name, Value::String(value));
+        }
+        payload.insert(form.code_field, json!(code.expose_secret()));
+
+        trace!("Answering two-factor challenge.");
+        let response = self
+            .send(Request::post(action_url, Value::Object(payload)).timeout(self.timeouts.login))
+            .await?;
+        trace!("Two-factor challenge answered.");
+
+        // A refused code shows the challenge again
+        if parse_two_factor_form(response.body.clone()).is_some() {
+            let message = "Failed to login: the two-factor code was refused.".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        Ok(Some(response))
+    }
+
+    /// Switch Aurion to the configured language code.
+    ///
+    /// Returns the html content of the main page reloaded in the new language,
+    /// or nothing if the switch request was not accepted.
+    async fn switch_language(&self) -> Result<Option<String>> {
+        let language_code = self.menu.read().unwrap().language_code();
+
+        // The language selection menu is the j_idt820 form element
+        let payload = json!({
+            "javax.faces.partial.ajax": Bool(true),
+            "javax.faces.source": "form:j_idt820",
+            "javax.faces.partial.execute": "form:j_idt820",
+            "javax.faces.behavior.event": "change",
+            "javax.faces.partial.event": "change",
+            "form": "form",
+            "form:j_idt820_focus": "",
+            "form:j_idt820_input": language_code.to_string(),
+            "javax.faces.ViewState": self.view_state.lock().unwrap().clone(),
+        });
+
+        trace!("Sending request to switch language to {}", language_code);
+        let response = self
+            .send(Request::post(self.pages.main_menu_url(), payload))
+            .await?;
+        trace!("Request to switch language sent");
+
+        if !response.status.is_success() {
+            warn!(
+                "Failed to switch language to {}: status {}",
+                language_code, response.status
+            );
+            return Ok(None);
+        }
+
+        // Reload the main page, whose view state and form ids may have changed
+        let response = self.send(Request::get(self.pages.service_url())).await?;
+        let text = response.body;
+        if let Some(view_state) = get_view_state(&text) {
+            *self.view_state.lock().unwrap() = Some(view_state);
+        }
+        if let Ok(form_id) = get_form_id(&text) {
+            *self.form_id.lock().unwrap() = Some(form_id);
+        }
+
+        Ok(Some(text))
+    }
+
+    /// Discover the schooling, user planning and groups planning menu ids by
+    /// their labels, in French or English, and reset the menu tree with them.
+    ///
+    /// This is done automatically on login when the menu ids were not given,
+    /// see [`AurionBuilder::discovering`].
+    pub async fn discover_menu_ids(&self) -> Result<()> {
+        let _navigation = self.navigation.lock().await;
+        trace!("Fetching the main page to discover the menu ids.");
+        let response = self.send(Request::get(self.pages.service_url())).await?;
+        self.discover_menu_ids_from(&response.body).await
+    }
+
+    /// Discover the menu ids from the html content of the main page.
+    async fn discover_menu_ids_from(&self, text: &str) -> Result<()> {
+        let roots = parse_menu_roots(text);
+        let find_root = |labels: &[&str]| {
+            roots
+                .iter()
+                .find(|(_, name)| {
+                    let name = name.to_lowercase();
+                    labels.iter().any(|label| name.contains(label))
+                })
+                .map(|(id, _)| id.clone())
+        };
+
+        let schooling_id = find_root(&["scolarité", "schooling"]);
+        let schooling_id = match schooling_id {
+            Some(schooling_id) => schooling_id,
+            None => {
+                let message = "Failed to discover the schooling menu id".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        // Some accounts have no groups branch at all
+        let groups_planning_id = find_root(&["plannings des groupes", "groups planning"]);
+        let groups_planning_id = groups_planning_id.unwrap_or_else(|| {
+            warn!("No groups planning branch found, group plannings are unavailable");
+            String::new()
+        });
+
+        // The user planning is a leaf of the schooling node
+        let language_code = self.menu.read().unwrap().language_code();
+        *self.menu.write().unwrap() = Menu::new(
+            language_code,
+            schooling_id.clone(),
+            "",
+            groups_planning_id.clone(),
+        );
+        let children = self.fetch_menu_child_nodes(schooling_id.clone()).await?;
+
+        // "Planning" is stripped from the labels, leaving "Mon" or "My"
+        let user_planning = children.iter().find(|node| {
+            let node = node.read().unwrap();
+            let name = node.name.to_lowercase();
+            node.is_leaf() && (name == "mon" || name == "my")
+        });
+        let user_planning_id = user_planning.and_then(|node| {
+            self.menu
+                .read()
+                .unwrap()
+                .menu_id_of(node)
+                .map(|menu_id| menu_id.to_string())
+        });
+        let user_planning_id = match user_planning_id {
+            Some(user_planning_id) => user_planning_id,
+            None => {
+                let message = "Failed to discover the user planning menu id".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        debug!(
+            "Discovered menu ids: schooling {}, user planning {}, groups planning {}",
+            schooling_id, user_planning_id, groups_planning_id
+        );
+        self.menu
+            .write()
+            .unwrap()
+            .set_user_planning_id(user_planning_id);
+
+        Ok(())
+    }
+
+    /// Check that the configured schooling, user planning and groups planning
+    /// menu ids exist in the live sidebar.
+    ///
+    /// The first wrong menu id is reported with
+    /// [`AurionError::InvalidMenuId`]. This is done automatically on login
+    /// when enabled with [`AurionBuilder::validate_menu_ids`].
+    pub async fn validate_menu_ids(&self) -> Result<()> {
+        let _navigation = self.navigation.lock().await;
+        trace!("Fetching the main page to validate the menu ids.");
+        let response = self.send(Request::get(self.pages.service_url())).await?;
+        self.validate_menu_ids_from(&response.body).await
+    }
+
+    /// Validate the menu ids from the html content of the main page.
+    async fn validate_menu_ids_from(&self, text: &str) -> Result<()> {
+        let roots = parse_menu_roots(text);
+        let invalid_menu_id = |name: &str, id: &str| {
+            let error = AurionError::InvalidMenuId {
+                name: name.to_string(),
+                id: id.to_string(),
+            };
+            error!("{}", error);
+            Err(Error::new(error))
+        };
+
+        for (name, id) in [
+            ("schooling_id", self.menu.read().unwrap().schooling_id()),
+            (
+                "groups_planning_id",
+                self.menu.read().unwrap().groups_planning_id(),
+            ),
+        ] {
+            // An empty groups planning menu id means the branch is absent
+            if id.is_empty() && name == "groups_planning_id" {
+                continue;
+            }
+            if !roots.iter().any(|(root_id, _)| root_id == id) {
+                return invalid_menu_id(name, id);
+            }
+        }
+
+        // The user planning is a child of the schooling node
+        let schooling_id = self.menu.read().unwrap().schooling_id().to_string();
+        self.load_nodes([schooling_id]).await?;
+        let user_planning_id = self.menu.read().unwrap().user_planning_id().to_string();
+        if self
+            .menu
+            .read()
+            .unwrap()
+            .get_menu_node(&user_planning_id)
+            .is_none()
+        {
+            return invalid_menu_id("user_planning_id", &user_planning_id);
+        }
 
-        Ok(std::str::from_utf8(cookie.as_bytes())?.to_string())
+        Ok(())
     }
 
     /// Get the menu child nodes of the given menu id.
@@ -174,12 +1135,28 @@ impl Aurion {
     /// multiple child nodes. This function returns the child nodes of the given
     /// menu id. Also, each node need to be loaded before being able to get its
     /// child nodes.
-    pub async fn get_menu_child_nodes<T: Into<String>>(
-        &mut self,
-        menu_id: T,
-    ) -> Result<Vec<Rc<RefCell<Node>>>> {
+    #[instrument(skip_all, fields(menu_id))]
+    pub async fn get_menu_child_nodes<I: Into<String>>(
+        &self,
+        menu_id: I,
+    ) -> Result<Vec<Arc<RwLock<Node>>>> {
         let menu_id = menu_id.into();
-        let menu_node = self.menu.get_menu_node(menu_id.clone());
+        Span::current().record("menu_id", &menu_id);
+        let _navigation = self.navigation.lock().await;
+        self.fetch_menu_child_nodes(menu_id).await
+    }
+
+    /// Get the menu child nodes of the given menu id, see
+    /// [`Aurion::get_menu_child_nodes`].
+    async fn fetch_menu_child_nodes(&self, menu_id: String) -> Result<Vec<Arc<RwLock<Node>>>> {
+        let menu_node = self.menu.read().unwrap().get_menu_node(menu_id.clone());
+
+        // The groups branch is absent for this account
+        if !self.menu.read().unwrap().has_group_plannings()
+            && menu_id == self.menu.read().unwrap().groups_planning_id()
+        {
+            return Err(no_group_plannings());
+        }
 
         if menu_node.is_none() {
             let message = format!(
@@ -191,10 +1168,12 @@ impl Aurion {
         }
 
         let menu_node = menu_node.unwrap();
-        let mut node = menu_node.borrow_mut();
 
         // Create the payload for the request
-        let j_idt = format!("form:j_idt{}", self.form_id.clone().unwrap_or_default());
+        let j_idt = format!(
+            "form:j_idt{}",
+            self.form_id.lock().unwrap().unwrap_or_default()
+        );
         let payload = json!({
             "javax.faces.partial.ajax": Bool(true),
             "javax.faces.source": j_idt.clone(),
@@ -206,45 +1185,44 @@ impl Aurion {
             "form:sauvegarde": "",
             "form:j_idt805:j_idt808_view": "basicDay",
             "form:j_idt820_focus": "",
-            "form:j_idt820_input": "",
-            "javax.faces.ViewState": self.view_state.clone().unwrap_or_default(),
+            "form:j_idt820_input": self.menu.read().unwrap().language_code().to_string(),
+            "javax.faces.ViewState": self.view_state.lock().unwrap().clone().unwrap_or_default(),
             "webscolaapp.Sidebar.ID_SUBMENU": menu_id.clone(),
         });
 
         // Send the request
         trace!("Beginning menu child nodes request.");
         let response = self
-            .client
-            .post(self.pages.main_menu_url())
-            .form(&payload)
-            .send()
+            .send(Request::post(self.pages.main_menu_url(), payload).idempotent())
             .await?;
         trace!("Menu child nodes request sent.");
 
         // Get the raw html data from the response
-        let text = response.text().await.unwrap();
+        let text = response.body;
         let splitter = "<update id=\"form:sidebar\"><![CDATA[";
         let splitted = text.split(splitter).collect::<Vec<&str>>();
 
         if splitted.len() < 2 {
-            let message = format!("Failed to get menu child nodes: invalid response");
+            let message = "Failed to get menu child nodes: invalid response".to_string();
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
         let raw_data = splitted[1].split("]]></update>").collect::<Vec<&str>>()[0];
 
-        // Parse the raw data to dyer::Response to support XPath
-        let body = dyer::Body::from(raw_data);
-        let mut response = dyer::Response::new(body);
+        // Parse the raw data to support XPath
+        let html = Html::parse(raw_data);
 
         // Get the child nodes of menu_id's menu
-        let result = response.xpath(&format!(
+        let result = html.xpath(&format!(
             "//li[contains(@class, \"{}\")]/ul/li",
             menu_id.clone()
         ));
 
-        // Parse the child nodes and add them to the menu tree
+        // Parse the child nodes and add them to the menu tree, locking the
+        // menu before the node like everywhere else
+        let mut menu = self.menu.write().unwrap();
+        let mut node = menu_node.write().unwrap();
         for child_node in &result {
             let is_parent = child_node
                 .clone()
@@ -265,7 +1243,7 @@ impl Aurion {
             // A node can either be a parent that holds unloaded submenus (children)
             // or a leaf. The parsing of the id for the two cases is
             // unfortunately different.
-            let parent = Rc::clone(&menu_node);
+            let parent = Arc::clone(&menu_node);
 
             if is_parent {
                 // the id is contained in the class of the <li>
@@ -278,15 +1256,15 @@ impl Aurion {
                         .split_once(" submenu_")
                         .unwrap()
                         .1
-                        .split_once(" ")
+                        .split_once(' ')
                         .unwrap()
                         .0
                 );
 
-                let child = Rc::new(RefCell::new(Node::new(id.clone(), name, Some(parent))));
+                let child = Arc::new(RwLock::new(Node::new(id.clone(), name, Some(parent))));
 
-                node.add_child(Rc::clone(&child));
-                self.menu.add_node(id, Rc::clone(&child));
+                node.add_child(Arc::clone(&child));
+                menu.add_node(id, Arc::clone(&child));
             } else {
                 // The id here is contained in the "onclick" attribute of the <a>
                 let id = format!(
@@ -297,7 +1275,7 @@ impl Aurion {
                         .split_once(" item_")
                         .unwrap()
                         .1
-                        .split_once(" ")
+                        .split_once(' ')
                         .unwrap()
                         .0
                 );
@@ -308,14 +1286,14 @@ impl Aurion {
                     .split_once("form:sidebar_menuid':'")
                     .unwrap()
                     .1
-                    .split_once("'")
+                    .split_once('\'')
                     .unwrap()
                     .0;
 
-                let child = Rc::new(RefCell::new(Node::new(page_id, name, Some(parent))));
+                let child = Arc::new(RwLock::new(Node::new(page_id, name, Some(parent))));
 
-                node.add_child(Rc::clone(&child));
-                self.menu.add_node(id, Rc::clone(&child));
+                node.add_child(Arc::clone(&child));
+                menu.add_node(id, Arc::clone(&child));
             }
         }
 
@@ -354,40 +1332,191 @@ impl Aurion {
     /// aurion.load_menu_nodes(vec!["submenu_1", "submenu_2"]).await;
     /// #     Ok(())
     /// # }
-    pub async fn load_menu_nodes<T: Into<String>, V: Into<Vec<T>>>(
-        &mut self,
+    #[instrument(skip_all)]
+    pub async fn load_menu_nodes<I: Into<String>, V: Into<Vec<I>>>(
+        &self,
         menu_nodes: V,
     ) -> Result<()> {
+        let _navigation = self.navigation.lock().await;
+        self.load_nodes(menu_nodes).await
+    }
+
+    /// Load the given menu nodes into the menu tree, see
+    /// [`Aurion::load_menu_nodes`].
+    async fn load_nodes<I: Into<String>, V: Into<Vec<I>>>(&self, menu_nodes: V) -> Result<()> {
         for menu_node in menu_nodes.into() {
             let menu_node = menu_node.into();
 
             // Check if node is loaded
-            if self.menu.is_node_loaded(menu_node.clone()) {
+            if self.menu.read().unwrap().is_node_loaded(menu_node.clone()) {
                 debug!("Node {} is already loaded", menu_node.clone());
                 continue;
             }
 
-            self.get_menu_child_nodes(menu_node.clone()).await?;
+            self.fetch_menu_child_nodes(menu_node.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Load the whole menu tree, walking it breadth-first from the schooling
+    /// and groups planning root nodes.
+    ///
+    /// Parent nodes are loaded down to `max_depth` levels below the root
+    /// nodes, a depth of 0 only loading the root nodes. This enumerates every
+    /// planning without knowing their menu ids in advance, at the cost of one
+    /// request per parent node.
+    pub async fn load_full_menu(&self, max_depth: usize) -> Result<()> {
+        let _navigation = self.navigation.lock().await;
+        let mut queue = VecDeque::from([(self.menu.read().unwrap().schooling_id().to_string(), 0)]);
+        if self.menu.read().unwrap().has_group_plannings() {
+            queue.push_back((
+                self.menu.read().unwrap().groups_planning_id().to_string(),
+                0,
+            ));
+        }
+
+        while let Some((menu_id, depth)) = queue.pop_front() {
+            let is_loaded = self.menu.read().unwrap().is_node_loaded(menu_id.clone());
+            let children = match is_loaded {
+                true => self
+                    .menu
+                    .read()
+                    .unwrap()
+                    .get_menu_node(menu_id.clone())
+                    .map(|node| node.read().unwrap().get_children().to_vec())
+                    .unwrap_or_default(),
+                false => self.fetch_menu_child_nodes(menu_id.clone()).await?,
+            };
+
+            if depth == max_depth {
+                continue;
+            }
+
+            for child in children {
+                let child = child.read().unwrap();
+                if child.id.starts_with("submenu_") {
+                    queue.push_back((child.id.clone(), depth + 1));
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Probe the features the logged in account can access.
+    ///
+    /// This loads the schooling and groups planning menu nodes, then looks
+    /// for the user planning node and for teacher plannings and grades nodes
+    /// by their labels among the loaded nodes.
+    pub async fn probe_capabilities(&self) -> Result<Capabilities> {
+        let _navigation = self.navigation.lock().await;
+        let schooling_id = self.menu.read().unwrap().schooling_id().to_string();
+        let groups_planning_id = self.menu.read().unwrap().groups_planning_id().to_string();
+
+        // The schooling node must be available for any account
+        self.load_nodes([schooling_id]).await?;
+
+        // The groups branch may not be available for this account
+        let has_group_plannings = self.menu.read().unwrap().has_group_plannings();
+        let group_plannings = match has_group_plannings {
+            true => match self.load_nodes([groups_planning_id.clone()]).await {
+                Ok(()) => self.menu.read().unwrap().is_node_loaded(groups_planning_id),
+                Err(e) => {
+                    warn!("Groups planning node could not be loaded: {}", e);
+                    false
+                }
+            },
+            false => false,
+        };
+
+        let own_planning = {
+            let menu = self.menu.read().unwrap();
+            menu.get_menu_node(menu.user_planning_id()).is_some()
+        };
+
+        // Look for the other features by their labels, in French or English
+        let has_node_named = |labels: &[&str]| {
+            self.menu.read().unwrap().nodes().any(|node| {
+                let name = node.read().unwrap().name.to_lowercase();
+                labels.iter().any(|label| name.contains(label))
+            })
+        };
+        let teacher_plannings = has_node_named(&["enseignant", "teacher"]);
+        let grades = has_node_named(&["notes", "grades"]);
+        let internships = has_node_named(&["stage", "internship"]);
+
+        let capabilities = Capabilities {
+            own_planning,
+            group_plannings,
+            teacher_plannings,
+            grades,
+            internships,
+        };
+        debug!("Capabilities: {:?}", capabilities);
+
+        Ok(capabilities)
+    }
+
     /// Get the class groups designated by class_group_id.
     /// A class can have multiple groups, for example, a class can have a
     /// group for the morning and a group for the afternoon. This function
     /// returns the groups designated by class_group_id.
-    pub async fn get_class_groups<T: Into<String>>(
+    pub async fn get_class_groups<I: Into<String>>(
         &self,
-        class_group_id: T,
+        class_group_id: I,
     ) -> Result<Vec<ClassGroup>> {
         let class_group_id = class_group_id.into();
 
-        // We need to check if the node is loaded. If it is not, we need
-        // to load it first because of Aurion's lazy-loading menu tree.
+        // Class group nodes can't be loaded without the groups branch
+        if !self.menu.read().unwrap().has_group_plannings()
+            && self
+                .menu
+                .read()
+                .unwrap()
+                .get_menu_node(class_group_id.clone())
+                .is_none()
+        {
+            return Err(no_group_plannings());
+        }
+
+        let menu_id = self.planning_page_id(class_group_id)?;
+        let _navigation = self.navigation.lock().await;
+        let text = self.open_planning_choice(menu_id.clone()).await?;
+        let class_groups = parse_planning_choices(text);
+
+        // Check if the class groups were found
+        if class_groups.is_empty() {
+            let message = "Class groups not found".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        let groups = class_groups
+            .into_iter()
+            .map(|choice| {
+                ClassGroup::new(choice.id, choice.name, menu_id.clone())
+                    .with_columns(choice.columns)
+            })
+            .collect();
+
+        Ok(groups)
+    }
+
+    /// Get the id of the planning choice page opened by the loaded leaf node
+    /// with the given menu id.
+    fn planning_page_id<I: Into<String>>(&self, class_group_id: I) -> Result<String> {
+        let class_group_id = class_group_id.into();
+
+        // We need to check if the node is loaded. If it is not, we need
+        // to load it first because of Aurion's lazy-loading menu tree.
 
         // Try to get the node from the menu tree
-        let node = self.menu.get_menu_node(class_group_id.clone());
+        let node = self
+            .menu
+            .read()
+            .unwrap()
+            .get_menu_node(class_group_id.clone());
 
         // Check if the node was found
         if node.is_none() {
@@ -400,115 +1529,771 @@ impl Aurion {
         let node = node.unwrap();
 
         // Check if the node is a leaf node
-        if !node.borrow().is_leaf() {
+        if !node.read().unwrap().is_leaf() {
             let message = format!("Node {} is not a leaf node", class_group_id.clone());
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
         // Check if the node is loaded
-        if !node.borrow().is_loaded() {
+        if !node.read().unwrap().is_loaded() {
             let message = format!("Node {} is not loaded", class_group_id.clone());
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
-        // Get the class groups
+        let menu_id = node.read().unwrap().id.clone();
+        Ok(menu_id)
+    }
+
+    /// Get the favorite plannings of the planning choice page opened by the
+    /// loaded leaf node with the given menu id, such as the class groups
+    /// node.
+    ///
+    /// Favorite plannings are flagged by a filled star in the planning
+    /// choice table.
+    pub async fn get_favorite_plannings<I: Into<String>>(
+        &self,
+        class_group_id: I,
+    ) -> Result<Vec<ClassGroup>> {
+        let menu_id = self.planning_page_id(class_group_id)?;
+        let _navigation = self.navigation.lock().await;
+        let text = self.open_planning_choice(menu_id.clone()).await?;
+
+        let favorites = parse_planning_choices(text)
+            .into_iter()
+            .filter(|choice| choice.favorite)
+            .map(|choice| {
+                ClassGroup::new(choice.id, choice.name, menu_id.clone())
+                    .with_columns(choice.columns)
+            })
+            .collect();
+
+        Ok(favorites)
+    }
+
+    /// Add the planning with the given id to the favorites of the planning
+    /// choice page opened by the loaded leaf node with the given menu id.
+    pub async fn add_favorite_planning<I: Into<String>>(
+        &self,
+        class_group_id: I,
+        planning_id: u32,
+    ) -> Result<()> {
+        self.update_favorite_planning(
+            class_group_id,
+            planning_id,
+            &["ajouter aux favoris", "add to favorites"],
+        )
+        .await
+    }
+
+    /// Remove the planning with the given id from the favorites of the
+    /// planning choice page opened by the loaded leaf node with the given
+    /// menu id.
+    pub async fn remove_favorite_planning<I: Into<String>>(
+        &self,
+        class_group_id: I,
+        planning_id: u32,
+    ) -> Result<()> {
+        self.update_favorite_planning(
+            class_group_id,
+            planning_id,
+            &[
+                "supprimer des favoris",
+                "retirer des favoris",
+                "remove from favorites",
+            ],
+        )
+        .await
+    }
+
+    /// Submit the planning with the given id with the favorites button whose
+    /// text contains one of the given labels.
+    async fn update_favorite_planning<I: Into<String>>(
+        &self,
+        class_group_id: I,
+        planning_id: u32,
+        button_labels: &[&str],
+    ) -> Result<()> {
+        let menu_id = self.planning_page_id(class_group_id)?;
+        let _navigation = self.navigation.lock().await;
+        let text = self.open_planning_choice(menu_id).await?;
+
+        // Check if the favorites button was found
+        let button_id = match get_button_form_id(&text, button_labels) {
+            Some(button_id) => button_id,
+            None => {
+                let message = "Favorites button not found".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
 
-        // Send the request to load the page for getting the class groups
-        let payload = self.default_parameters(node.borrow().id.clone());
-        trace!("Sending first request to get class groups");
+        debug!("Updating favorite planning {}", planning_id);
         let response = self
-            .client
-            .post(self.pages.main_menu_url())
-            .form(&payload)
-            .send()
+            .submit_planning_choice(
+                self.pages.planning_choice_url(),
+                &text,
+                &[planning_id],
+                button_id,
+            )
             .await?;
-        trace!("Response received from get class groups request");
 
-        // Parse the response
-        let headers = response.headers();
+        // Check if the favorites were updated
+        if !response.status.is_success() && response.location().is_none() {
+            let message = format!("Failed to update favorite planning {}", planning_id);
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        Ok(())
+    }
+
+    /// Get the class groups designated by class_group_id whose name or
+    /// columns contain the given query, ignoring case.
+    ///
+    /// See `get_class_groups` and [`ClassGroup::matches`].
+    pub async fn get_class_groups_matching<I: Into<String>, Q: AsRef<str>>(
+        &self,
+        class_group_id: I,
+        query: Q,
+    ) -> Result<Vec<ClassGroup>> {
+        let groups = self.get_class_groups(class_group_id).await?;
+        Ok(groups
+            .into_iter()
+            .filter(|group| group.matches(query.as_ref()))
+            .collect())
+    }
+
+    /// Get the teachers whose planning can be fetched.
+    ///
+    /// The teachers planning node is looked up by its label among the loaded
+    /// menu nodes, so its parent node must be loaded first with
+    /// `load_menu_nodes`.
+    pub async fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["enseignants", "teachers"])?;
+        let text = self.open_planning_choice(menu_id).await?;
+        let teachers = parse_planning_choices(text);
+
+        // Check if the teachers were found
+        if teachers.is_empty() {
+            let message = "Teachers not found".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        let teachers = teachers
+            .into_iter()
+            .map(|choice| Teacher::new(choice.id, choice.name))
+            .collect();
+
+        Ok(teachers)
+    }
+
+    /// Navigate to the planning choice page of the given menu page id and
+    /// return its html content.
+    ///
+    /// The planning choice page lists the plannings (class groups, rooms,
+    /// etc.) that can be selected from a menu leaf node.
+    async fn open_planning_choice<M: Into<String>>(&self, menu_id: M) -> Result<String> {
+        // Send the request to load the planning choice page
+        let payload = self.default_parameters(menu_id);
+        trace!("Sending request to open the planning choice page");
+        let response = self
+            .send(Request::post(self.pages.main_menu_url(), payload))
+            .await?;
+        trace!("Response received from open planning choice page request");
 
         // Check if the response was successful
-        if !headers.contains_key("location") {
-            let message = format!("Response to get class groups was not successful");
+        if response.location().is_none() {
+            let message =
+                "Response to open the planning choice page was not successful".to_string();
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
-        // Send the request to get the class groups
-        trace!("Sending request to get class groups");
+        // Send the request to get the planning choice page
+        trace!("Sending request to get the planning choice page");
         let response = self
-            .client
-            .get(self.pages.planning_choice_url())
-            .send()
+            .send(Request::get(self.pages.planning_choice_url()))
             .await?;
-        trace!("Response received from get class groups request");
+        trace!("Response received from get planning choice page request");
 
-        // Parse the response data to dyer::Response to support XPath
-        let body = dyer::Body::from(response.text().await.unwrap());
-        let mut response = dyer::Response::new(body);
+        Ok(response.body)
+    }
+
+    /// Check whether the planning page with the given id still exists,
+    /// without fetching its schedule.
+    ///
+    /// The id is either the id of the page opened by a planning leaf node,
+    /// or the menu id of a loaded leaf node (see [`Menu`]). This only asks
+    /// Aurion to open the page, which is enough to flag stale ids in a
+    /// configuration before fetching anything.
+    pub async fn planning_exists<I: Into<String>>(&self, id: I) -> Result<bool> {
+        let id = id.into();
+        let page_id = match self.menu.read().unwrap().get_menu_node(id.clone()) {
+            Some(node) => node.read().unwrap().id.clone(),
+            None => id,
+        };
+
+        // Aurion redirects to the page only if it exists
+        debug!("Checking if planning {} exists", page_id);
+        let _navigation = self.navigation.lock().await;
+        let payload = self.default_parameters(page_id);
+        let response = self
+            .send(Request::post(self.pages.main_menu_url(), payload))
+            .await?;
 
-        // Get the class groups
-        let class_groups = response.xpath("//div[@id=\"form:dataTableFavori\"]//tbody/tr");
+        Ok(response.location().is_some())
+    }
 
-        // Check if the class groups were found
-        if class_groups.is_empty() {
-            let message = format!("Class groups not found");
+    /// Open the page of the given menu page id, returning its url and its
+    /// html content.
+    async fn open_page<M: Into<String>>(&self, menu_id: M) -> Result<(Url, String)> {
+        let payload = self.default_parameters(menu_id);
+        trace!("Sending request to open a page");
+        let response = self
+            .send(Request::post(self.pages.main_menu_url(), payload))
+            .await?;
+
+        // Check if the response redirects to the page
+        let url = match response.location() {
+            Some(location) => self.pages.service_url().join(location)?,
+            None => {
+                let message = "Response to open the page was not successful".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        trace!("Sending request to get the page");
+        let response = self.send(Request::get(url.clone())).await?;
+        Ok((url, response.body))
+    }
+
+    /// Get the administrative documents that can be downloaded.
+    ///
+    /// The documents node is looked up by its label among the loaded menu
+    /// nodes, so the schooling node must be loaded first with
+    /// `load_menu_nodes`.
+    pub async fn list_documents(&self) -> Result<Vec<Document>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["documents"])?;
+        let (_, text) = self.open_page(menu_id).await?;
+
+        let documents = parse_documents(text)
+            .into_iter()
+            .map(|row| Document::new(row.id, row.name))
+            .collect();
+
+        Ok(documents)
+    }
+
+    /// Get the personal information of the logged in user.
+    ///
+    /// The personal information node is looked up by its label among the
+    /// loaded menu nodes, so the schooling node must be loaded first with
+    /// `load_menu_nodes`.
+    pub async fn get_profile(&self) -> Result<Profile> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&[
+            "informations personnelles",
+            "mes informations",
+            "personal information",
+        ])?;
+        let (_, text) = self.open_page(menu_id).await?;
+
+        match parse_profile(text) {
+            Some(profile) => Ok(profile),
+            None => {
+                let message = "Failed to parse the profile".to_string();
+                error!("{}", message);
+                telemetry::record_parse_failure("profile");
+                Err(Error::msg(message))
+            }
+        }
+    }
+
+    /// Get the students of the class group with the given id, listed in the
+    /// trombinoscope.
+    ///
+    /// The group id is the id of the group in the planning choice table of
+    /// the trombinoscope page. The trombinoscope node is looked up by its
+    /// label among the loaded menu nodes, so its parent node must be loaded
+    /// first with `load_menu_nodes`.
+    pub async fn get_roster(&self, group_id: u32) -> Result<Vec<Student>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["trombinoscope"])?;
+        let (url, text) = self.open_page(menu_id).await?;
+
+        let button_id = match get_planning_choice_form_id(&text) {
+            Some(button_id) => button_id,
+            None => {
+                let message = "Trombinoscope form id not found".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        debug!("Selecting group {} in the trombinoscope", group_id);
+        let response = self
+            .submit_planning_choice(url.clone(), &text, &[group_id], button_id)
+            .await?;
+
+        // The roster is either sent back or redirected to
+        let text = match response.location() {
+            Some(location) => {
+                let location = url.join(location)?;
+                self.send(Request::get(location)).await?.body
+            }
+            None if response.status.is_success() => response.body,
+            None => {
+                let message = format!("Failed to select group {} in the trombinoscope", group_id);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        let mut students = Vec::new();
+        for cell in parse_roster(text) {
+            let photo_url = match cell.photo_src {
+                Some(photo_src) => Some(url.join(&photo_src)?.to_string()),
+                None => None,
+            };
+            students.push(Student::new(cell.name, photo_url));
+        }
+
+        Ok(students)
+    }
+
+    /// Download the photo of a student listed by `get_roster`.
+    pub async fn get_student_photo(&self, student: &Student) -> Result<Bytes> {
+        let photo_url = match &student.photo_url {
+            Some(photo_url) => Url::parse(photo_url)?,
+            None => {
+                let message = format!("Student {} has no photo", student.name);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        let response = self.send(Request::get(photo_url)).await?;
+        if !response.status.is_success() {
+            let message = format!("Failed to download the photo of {}", student.name);
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
-        // Parse the class groups
-        let mut groups = Vec::new();
-        for class_group in class_groups {
-            let id = class_group
-                .get_attribute("data-rk")
-                .unwrap()
-                .parse::<u32>()
-                .unwrap();
-            let name = class_group
-                .get_last_element_child()
-                .unwrap()
-                .get_last_element_child()
-                .unwrap()
-                .get_content();
-            groups.push(ClassGroup::new(id, name));
+        Ok(response.bytes)
+    }
+
+    /// Get the exams listed in the exams page, with their assessment
+    /// metadata.
+    ///
+    /// The exams node is looked up by its label among the loaded menu nodes,
+    /// so its parent node must be loaded first with `load_menu_nodes`. The
+    /// schedule event of an exam can then be found with [`Exam::find_event`].
+    pub async fn get_exams(&self) -> Result<Vec<Exam>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["examens", "épreuves", "exams"])?;
+        let (_, text) = self.open_page(menu_id).await?;
+
+        Ok(parse_exams(text, self.timezone))
+    }
+
+    /// Get the internship offers published in Aurion.
+    ///
+    /// The offers node is looked up by its label among the loaded menu nodes,
+    /// so its parent node must be loaded first with `load_menu_nodes`. Only
+    /// the fields shown in the offers table are set, see
+    /// `get_internship_offer` for the full offer.
+    pub async fn get_internship_offers(&self) -> Result<Vec<InternshipOffer>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["offres de stage", "internship offers"])?;
+        let (_, text) = self.open_page(menu_id).await?;
+
+        Ok(parse_internship_offers(text))
+    }
+
+    /// Get the internship offer with the given id, listed by
+    /// `get_internship_offers`, completed with the fields of its detail page.
+    pub async fn get_internship_offer(&self, offer_id: &str) -> Result<InternshipOffer> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["offres de stage", "internship offers"])?;
+        let (url, text) = self.open_page(menu_id).await?;
+
+        // Find the offer and the button opening its detail page
+        let row = parse_table(&text)
+            .into_iter()
+            .find(|row| row.id == offer_id);
+        let (mut offer, button_id) = match row {
+            Some(row) if row.button_id.is_some() => {
+                (parse_internship_offer(&row), row.button_id.unwrap())
+            }
+            _ => {
+                let message = format!("Internship offer {} not found", offer_id);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        debug!("Opening internship offer {}", offer_id);
+        let response = self.press_button(&url, &text, &button_id).await?;
+
+        // The detail page is either sent back or redirected to
+        let text = match response.location() {
+            Some(location) => {
+                let location = url.join(location)?;
+                self.send(Request::get(location)).await?.body
+            }
+            None if response.status.is_success() => response.body,
+            None => {
+                let message = format!("Failed to open internship offer {}", offer_id);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        parse_internship_offer_details(&mut offer, text);
+        Ok(offer)
+    }
+
+    /// Get the internships of the user, listed in the internship record.
+    ///
+    /// The internship record node is looked up by its label among the loaded
+    /// menu nodes, so its parent node must be loaded first with
+    /// `load_menu_nodes`.
+    pub async fn get_internships(&self) -> Result<Vec<Internship>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["mes stages", "my internships"])?;
+        let (_, text) = self.open_page(menu_id).await?;
+
+        Ok(parse_internships(text))
+    }
+
+    /// Download the administrative document with the given id, listed by
+    /// `list_documents`.
+    pub async fn download_document(&self, document_id: &str) -> Result<Bytes> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["documents"])?;
+        let (url, text) = self.open_page(menu_id).await?;
+
+        // Find the download button of the document
+        let button_id = parse_documents(&text)
+            .into_iter()
+            .find(|row| row.id == document_id)
+            .and_then(|row| row.button_id);
+        let button_id = match button_id {
+            Some(button_id) => button_id,
+            None => {
+                let message = format!("Document {} not found", document_id);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        self.download(&url, &text, &button_id).await
+    }
+
+    /// Get the grade bulletins published for the user.
+    ///
+    /// The report cards node is looked up by its label among the loaded menu
+    /// nodes, so the schooling node must be loaded first with
+    /// `load_menu_nodes`.
+    pub async fn list_report_cards(&self) -> Result<Vec<ReportCard>> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["bulletins", "relevés de notes", "report cards"])?;
+        let (_, text) = self.open_page(menu_id).await?;
+
+        let report_cards = parse_table(text)
+            .into_iter()
+            .map(|row| {
+                let name = row
+                    .cells
+                    .iter()
+                    .map(|(_, value)| value.clone())
+                    .find(|value| !value.is_empty())
+                    .unwrap_or_default();
+                let semester = row
+                    .get(&["semestre", "période", "semester", "period"])
+                    .unwrap_or_else(|| name.clone());
+                ReportCard::new(row.id, semester, name)
+            })
+            .collect();
+
+        Ok(report_cards)
+    }
+
+    /// Download the grade bulletin of the given semester, as a PDF.
+    ///
+    /// The semester is either the id or the semester of a report card listed
+    /// by `list_report_cards`, compared case-insensitively.
+    pub async fn download_report_card(&self, semester: &str) -> Result<Bytes> {
+        let _navigation = self.navigation.lock().await;
+        let menu_id = self.find_leaf_node(&["bulletins", "relevés de notes", "report cards"])?;
+        let (url, text) = self.open_page(menu_id).await?;
+
+        // Find the download button of the report card
+        let button_id = parse_table(&text)
+            .into_iter()
+            .find(|row| {
+                let row_semester = row
+                    .get(&["semestre", "période", "semester", "period"])
+                    .unwrap_or_default();
+                row.id == semester || row_semester.eq_ignore_ascii_case(semester)
+            })
+            .and_then(|row| row.button_id);
+        let button_id = match button_id {
+            Some(button_id) => button_id,
+            None => {
+                let message = format!("Report card of semester {} not found", semester);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        self.download(&url, &text, &button_id).await
+    }
+
+    /// Download the school certificate of the user, as a PDF.
+    ///
+    /// The certificate is downloaded from its own page when the schooling
+    /// menu has one, or else from the documents page. In both cases the node
+    /// is looked up by its label among the loaded menu nodes, so the
+    /// schooling node must be loaded first with `load_menu_nodes`.
+    pub async fn download_school_certificate(&self) -> Result<Bytes> {
+        let labels = [
+            "certificat de scolarité",
+            "attestation de scolarité",
+            "school certificate",
+        ];
+
+        // Download the certificate from the documents page if it has no page
+        let menu_id = match self.find_leaf_node(&labels) {
+            Ok(menu_id) => menu_id,
+            Err(_) => {
+                let document = self.list_documents().await?.into_iter().find(|document| {
+                    let name = document.name.to_lowercase();
+                    labels.iter().any(|label| name.contains(label))
+                });
+                return match document {
+                    Some(document) => self.download_document(&document.id).await,
+                    None => {
+                        let message = "School certificate not found".to_string();
+                        error!("{}", message);
+                        Err(Error::msg(message))
+                    }
+                };
+            }
+        };
+
+        let _navigation = self.navigation.lock().await;
+        let (url, text) = self.open_page(menu_id).await?;
+        let button_id =
+            get_button_form_id(&text, &["télécharger", "download", "imprimer", "print"]);
+        let button_id = match button_id {
+            Some(button_id) => format!("form:j_idt{}", button_id),
+            None => {
+                let message = "School certificate download button not found".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        self.download(&url, &text, &button_id).await
+    }
+
+    /// Submit the form of the page at `url`, whose html content is `text`,
+    /// with the button with the given id.
+    async fn press_button(&self, url: &Url, text: &str, button_id: &str) -> Result<Response> {
+        self.send(button_request(url, text, button_id)).await
+    }
+
+    /// Download the file sent when submitting the form of the page at `url`,
+    /// whose html content is `text`, with the button with the given id.
+    async fn download(&self, url: &Url, text: &str, button_id: &str) -> Result<Bytes> {
+        debug!("Downloading the file of button {}", button_id);
+        let response = self
+            .send(button_request(url, text, button_id).timeout(self.timeouts.download))
+            .await?;
+
+        // An html page is sent back instead of the file on failure
+        let is_html = response
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.contains("html"));
+        if !response.status.is_success() || is_html {
+            let message = "Response to download the file was not valid".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
         }
 
-        Ok(groups)
+        Ok(response.bytes)
+    }
+
+    /// Select the plannings with the given ids in the planning choice page
+    /// whose html content is `text`, to prepare their merged schedule to be
+    /// fetched.
+    async fn select_plannings(&self, text: &str, planning_ids: &[u32]) -> Result<()> {
+        let form_id = get_planning_choice_form_id(text);
+
+        // Check if the submit button id was found
+        if form_id.is_none() {
+            let message = "Planning choice form id not found".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        trace!("Sending request to select plannings {:?}", planning_ids);
+        let response = self
+            .submit_planning_choice(
+                self.pages.planning_choice_url(),
+                text,
+                planning_ids,
+                form_id.unwrap(),
+            )
+            .await?;
+        trace!("Response received from select plannings request");
+
+        // Check if the response redirects to the planning page
+        if response.location().is_none() {
+            let message = format!("Failed to select plannings {:?}", planning_ids);
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        Ok(())
+    }
+
+    /// Submit the planning choice page at `url` whose html content is `text`
+    /// with the given plannings selected, using the button with the given id.
+    async fn submit_planning_choice(
+        &self,
+        url: Url,
+        text: &str,
+        planning_ids: &[u32],
+        button_id: u16,
+    ) -> Result<Response> {
+        let view_state = get_view_state(text);
+        let selection = planning_ids
+            .iter()
+            .map(|planning_id| planning_id.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let j_idt = format!("form:j_idt{}", button_id);
+        let payload = json!({
+            "form": "form",
+            "form:largeurDivCenter": "",
+            "form:sauvegarde": "",
+            "form:offsetTop": "",
+            "form:search-texte": "",
+            "form:search-texte-avancer": "",
+            "form:input-expression-exacte": "",
+            "form:input-un-des-mots": "",
+            "form:input-aucun-des-mots": "",
+            "form:input-nombre-debut": "",
+            "form:input-nombre-fin": "",
+            "form:calendarDebut_input": "",
+            "form:calendarFin_input": "",
+            "form:dataTableFavori_reflowDropdown": "0",
+            "form:dataTableFavori_checkbox": "on",
+            "form:dataTableFavori_selection": selection,
+            j_idt: "",
+            "javax.faces.ViewState": view_state,
+        });
+
+        self.send(Request::post(url, payload)).await
     }
 
     /// Get the lazy-loaded schedule previously initialized by either calling
     /// `get_user_schedule` or `get_group_schedule`.
     /// The schedule is returned as a vector of `Value`s.
+    #[instrument(skip(self))]
     async fn get_schedule(
         &self,
         start: Option<DateTime<Utc>>,
         end: Option<DateTime<Utc>>,
+        chunked: bool,
     ) -> Result<Vec<Event>> {
+        let (schedule_form_id, view_state) = self.open_schedule().await?;
+
+        // Parse start and end dates
+        let start = start.unwrap_or(self.start);
+        let end = end.unwrap_or(self.end);
+
+        // Fetch long ranges one chunk after the other
+        let chunks = match chunked {
+            true => self.schedule_chunks(start, end),
+            false => vec![DateRange::new(start, end)],
+        };
+        let mut schedule = Vec::new();
+        for chunk in &chunks {
+            let events = self
+                .get_schedule_chunk(schedule_form_id, &view_state, chunk)
+                .await?;
+            schedule.extend(events);
+        }
+
+        // Events spanning two chunks are listed in both
+        if chunks.len() > 1 {
+            schedule = dedup(schedule);
+        }
+
+        Ok(schedule)
+    }
+
+    /// Open the planning page initialized by either `get_user_schedule` or
+    /// `get_group_schedule`, returning its schedule form id and view state.
+    async fn open_schedule(&self) -> Result<(u8, Option<String>)> {
         // Send the request to get the schedule form id
         trace!("Sending request to get schedule form id");
-        let response = self.client.get(self.pages.planning_url()).send().await?;
+        let response = self.send(Request::get(self.pages.planning_url())).await?;
         trace!("Request to get schedule form id sent");
 
         // Parse the response
-        let text = response.text().await.unwrap();
-        let schedule_form_id = get_schedule_form_id(text.clone());
+        let text = response.body;
+        let schedule_form_id = get_schedule_form_id(text.clone())?;
         let view_state = get_view_state(text.clone());
 
-        // Check if the form id was found
-        if schedule_form_id.is_none() {
-            let message = format!("Schedule form id not found");
-            return Err(Error::msg(message));
+        // Keep the schedule view to request the details of its events
+        if let Some(view_state) = &view_state {
+            *self.schedule_view.lock().unwrap() = Some(ScheduleView {
+                form_id: schedule_form_id,
+                view_state: view_state.clone(),
+                served: None,
+                chunks: HashMap::new(),
+            });
         }
 
-        // Parse the form id
-        let schedule_form_id = schedule_form_id.unwrap();
+        Ok((schedule_form_id, view_state))
+    }
 
-        // Parse start and end dates
-        let start = start.unwrap_or_else(|| self.start);
-        let end = end.unwrap_or_else(|| self.end);
+    /// Split a schedule date range into the chunks to fetch, see
+    /// [`AurionBuilder::schedule_chunk_size`].
+    fn schedule_chunks(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<DateRange> {
+        let range = DateRange::new(start, end);
+        match self
+            .schedule_chunk_size
+            .and_then(|size| chrono::Duration::from_std(size).ok())
+        {
+            Some(size) => range.split(size),
+            None => vec![range],
+        }
+    }
+
+    /// Get the events of a chunk of the schedule opened in the planning
+    /// page.
+    async fn get_schedule_chunk(
+        &self,
+        schedule_form_id: u8,
+        view_state: &Option<String>,
+        chunk: &DateRange,
+    ) -> Result<Vec<Event>> {
+        let (start, end) = (chunk.start, chunk.end);
+        trace!("Getting schedule chunk from {} to {}", start, end);
 
         // Send the request to get the schedule
         let j_idt = format!("form:j_idt{}", schedule_form_id);
@@ -526,88 +2311,966 @@ impl Aurion {
 
         trace!("Sending request to get schedule");
         let response = self
-            .client
-            .post(self.pages.planning_url())
-            .form(&payload)
-            .send()
+            .send(
+                Request::post(self.pages.planning_url(), payload)
+                    .idempotent()
+                    .timeout(self.timeouts.schedule),
+            )
             .await?;
         trace!("Request to get schedule sent");
 
         // Parse the response
-        let text = response.text().await.unwrap();
+        let text = response.body;
         let splitter = "<![CDATA[{\"events\" : ";
-        let splitted = text.split_once(splitter);
+        let data = text
+            .split_once(splitter)
+            .and_then(|(_, data)| data.split_once("}]]></update>"));
 
         // Check if the response was valid
-        if splitted.is_none() {
-            let message = format!("Response to get schedule was not valid");
-            return Err(Error::msg(message));
-        }
-
-        let data = splitted.unwrap().1.split_once("}]]></update>").unwrap().0;
+        let data = match data {
+            Some((data, _)) => data,
+            None => {
+                let message = "Response to get schedule was not valid".to_string();
+                telemetry::record_parse_failure("schedule");
+                return Err(Error::msg(message));
+            }
+        };
 
         // Parse the schedule
         let mut schedule: Vec<Event> = Vec::new();
-        let raw_schedule: Vec<RawEvent> = serde_json::from_str(data)?;
+        let mut raw_schedule: Vec<RawEvent> = serde_json::from_str(data).inspect_err(|_| {
+            telemetry::record_parse_failure("schedule");
+        })?;
+
+        // Check for daylight saving time mismatches
+        for anomaly in check_dst(&mut raw_schedule, self.timezone, self.dst_correction) {
+            warn!(
+                "Event {} is shifted by {} minutes compared to its title{}",
+                anomaly.event_id,
+                anomaly.offset.num_minutes(),
+                if anomaly.corrected { ", corrected" } else { "" }
+            );
+        }
+
         for raw_event in raw_schedule {
-            let event = Event::from_raw_event(raw_event)?;
+            // Skip the events that can't be parsed rather than failing the
+            // whole schedule
+            let event = match Event::from_raw_event_with(raw_event, &self.kind_mapping) {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("Skipping event: {}", e);
+                    telemetry::record_parse_failure("event");
+                    continue;
+                }
+            };
+            if event.diagnostic.is_some() {
+                telemetry::record_parse_failure("event_title");
+            }
+            self.details_cache.observe(&event);
             schedule.push(event);
         }
 
+        // Keep the chunk of each event, to serve it again to get the details
+        // of its events
+        if let Some(schedule_view) = self.schedule_view.lock().unwrap().as_mut() {
+            schedule_view.served = Some(*chunk);
+            for event in &schedule {
+                schedule_view.chunks.insert(event.id.clone(), *chunk);
+            }
+        }
+
+        Ok(schedule)
+    }
+
+    /// Get the details of an event, as shown by Aurion when the event is
+    /// clicked.
+    ///
+    /// Aurion looks the event up among the events it served last, so the
+    /// event must belong to the last fetched schedule. When the schedule was
+    /// fetched in chunks, see [`AurionBuilder::schedule_chunk_size`], the
+    /// chunk of the event is served again first if another chunk was served
+    /// since. The details are cached when enabled with
+    /// [`AurionBuilder::details_cache_ttl`].
+    pub async fn get_event_details(&self, event_id: &EventId) -> Result<EventDetails> {
+        // Check if the details are cached
+        if let Some(details) = self.details_cache.get(event_id) {
+            return Ok(details);
+        }
+
+        debug!("Getting details of event {}", event_id);
+        let _navigation = self.navigation.lock().await;
+        let schedule_view = self
+            .schedule_view
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|schedule_view| {
+                let chunk = schedule_view
+                    .chunks
+                    .get(event_id)
+                    .filter(|chunk| schedule_view.served.as_ref() != Some(*chunk))
+                    .copied();
+                (
+                    schedule_view.form_id,
+                    schedule_view.view_state.clone(),
+                    chunk,
+                )
+            });
+        let (schedule_form_id, view_state, chunk) = match schedule_view {
+            Some(schedule_view) => schedule_view,
+            None => {
+                let message = "No schedule fetched to get the event details from".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        // Aurion only knows the events of the chunk it served last
+        if let Some(chunk) = chunk {
+            debug!("Serving the schedule chunk of event {} again", event_id);
+            self.get_schedule_chunk(schedule_form_id, &Some(view_state.clone()), &chunk)
+                .await?;
+        }
+
+        // Send the request to select the event
+        let j_idt = format!("form:j_idt{}", schedule_form_id);
+        let payload = json!({
+            "javax.faces.partial.ajax": Bool(true),
+            "javax.faces.source": j_idt.clone(),
+            "javax.faces.partial.execute": j_idt.clone(),
+            "javax.faces.partial.render": "form:modaleDetail",
+            "javax.faces.behavior.event": "eventSelect",
+            "javax.faces.partial.event": "eventSelect",
+            format!("{}_selectedEventId", j_idt.clone()): event_id.to_string(),
+            "form": "form",
+            "javax.faces.ViewState": view_state,
+        });
+
+        trace!("Sending request to get event details");
+        let response = self
+            .send(Request::post(self.pages.planning_url(), payload).idempotent())
+            .await?;
+        trace!("Request to get event details sent");
+
+        match parse_event_details(event_id, response.body) {
+            Some(details) => {
+                self.details_cache.insert(&details);
+                Ok(details)
+            }
+            None => {
+                let message = "Response to get event details was not valid".to_string();
+                error!("{}", message);
+                telemetry::record_parse_failure("event_details");
+                Err(Error::msg(message))
+            }
+        }
+    }
+
+    /// Get the schedule of a class group.
+    /// The class group is one of the groups returned by `get_class_groups`.
+    pub async fn get_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        self.fetch_group_schedule(group, start, end, true).await
+    }
+
+    /// Get the schedule of a class group, fetched in chunks if `chunked`.
+    async fn fetch_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        chunked: bool,
+    ) -> Result<Vec<Event>> {
+        debug!("Getting schedule of group {}", group.id);
+        let mut schedule = self
+            .get_planning_schedule(&group.menu_id, &[group.id], start, end, chunked)
+            .await?;
+        for event in &mut schedule {
+            event.group_id = Some(group.group_id());
+        }
+
+        Ok(schedule)
+    }
+
+    /// Get the schedule of a room.
+    ///
+    /// The room id is the id of the room in the planning choice table of the
+    /// rooms planning menu node, which can be listed like class groups with
+    /// `get_class_groups`. The rooms planning node is looked up by its label
+    /// among the loaded menu nodes, so its parent node must be loaded first
+    /// with `load_menu_nodes`.
+    pub async fn get_room_schedule(
+        &self,
+        room_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        debug!("Getting schedule of room {}", room_id);
+        let menu_id = self.find_leaf_node(&["salles", "rooms"])?;
+        self.get_planning_schedule(&menu_id, &[room_id], start, end, true)
+            .await
+    }
+
+    /// Get the schedule of a teacher, listed by `get_teachers`.
+    ///
+    /// The teachers planning node is looked up by its label among the loaded
+    /// menu nodes, so its parent node must be loaded first with
+    /// `load_menu_nodes`.
+    pub async fn get_teacher_schedule(
+        &self,
+        teacher_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        debug!("Getting schedule of teacher {}", teacher_id);
+        let menu_id = self.find_leaf_node(&["enseignants", "teachers"])?;
+        self.get_planning_schedule(&menu_id, &[teacher_id], start, end, true)
+            .await
+    }
+
+    /// Find the id of the loaded leaf node whose label contains one
+    /// of the given labels.
+    fn find_leaf_node(&self, labels: &[&str]) -> Result<String> {
+        let node = self
+            .menu
+            .read()
+            .unwrap()
+            .nodes()
+            .find(|node| {
+                let node = node.read().unwrap();
+                let name = node.name.to_lowercase();
+                node.is_leaf() && labels.iter().any(|label| name.contains(label))
+            })
+            .cloned();
+
+        match node {
+            Some(node) => Ok(node.read().unwrap().id.clone()),
+            None => {
+                let message = format!(
+                    "Node labelled {:?} not found, its parent node might not be loaded",
+                    labels
+                );
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        }
+    }
+
+    /// Get the merged schedule of the plannings listed in the planning choice
+    /// page of the given menu page id.
+    #[instrument(skip(self))]
+    async fn get_planning_schedule(
+        &self,
+        menu_id: &str,
+        planning_ids: &[u32],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        chunked: bool,
+    ) -> Result<Vec<Event>> {
+        // Check if the schedule is cached
+        let planning_ids_key = planning_ids
+            .iter()
+            .map(|planning_id| planning_id.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        let cache_id = format!("{}:{}", menu_id, planning_ids_key);
+        let start = start.unwrap_or(self.start);
+        let end = end.unwrap_or(self.end);
+        validate_range(start, end)?;
+        if let Some(schedule) = self.cache.get(&cache_id, start, end) {
+            return Ok(schedule);
+        }
+
+        // Navigate to the planning choice page and select the planning
+        let _navigation = self.navigation.lock().await;
+        let text = self.open_planning_choice(menu_id).await?;
+        self.select_plannings(&text, planning_ids).await?;
+
+        // Send the request to get the planning's schedule
+        let schedule = self.get_schedule(Some(start), Some(end), chunked).await?;
+        self.cache.insert(&cache_id, start, end, &schedule);
+
         Ok(schedule)
     }
 
-    // pub async fn get_group_schedule<T: Into<String>>(
-    //     &mut self,
-    //     group_id: T,
-    //     start: Option<DateTime<Utc>>,
-    //     end: Option<DateTime<Utc>>,
-    // )
+    /// Get the merged schedule of multiple class groups, selected together
+    /// in a single submission of the planning choice page.
+    ///
+    /// All the groups must be listed in the same planning choice page, that
+    /// is share the same `menu_id`. As the schedule is merged by Aurion, the
+    /// group of each event is unknown and left empty.
+    pub async fn get_combined_schedule(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        let menu_id = match groups.first() {
+            Some(group) => group.menu_id.clone(),
+            None => {
+                let message = "No class group to get the combined schedule of".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        // Check that the groups can be selected together
+        if groups.iter().any(|group| group.menu_id != menu_id) {
+            let message =
+                "Class groups of different planning choice pages can't be combined".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        let group_ids = groups.iter().map(|group| group.id).collect::<Vec<u32>>();
+        debug!("Getting combined schedule of groups {:?}", group_ids);
+        self.get_planning_schedule(&menu_id, &group_ids, start, end, true)
+            .await
+    }
+
+    /// Get the schedules of multiple class groups.
+    ///
+    /// Aurion's JSF pages are stateful: the session holds a single current
+    /// view, so the navigation, selection and fetch steps of two groups can't
+    /// be interleaved on the same session. The groups are therefore fetched
+    /// one after the other, and the first failure aborts the whole fetch.
+    /// To parallelize, use one logged in `Aurion` instance per session.
+    pub async fn get_group_schedules(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<HashMap<ClassGroup, Vec<Event>>> {
+        let mut schedules = HashMap::with_capacity(groups.len());
+        for group in groups {
+            let schedule = self.get_group_schedule(group, start, end).await?;
+            schedules.insert(group.clone(), schedule);
+        }
+
+        Ok(schedules)
+    }
+
+    /// Get the plannings of the user listed in the schooling menu, the
+    /// configured user planning first.
+    ///
+    /// Some accounts, such as double degree ones, have several plannings,
+    /// which can then be selected with `select_user_planning`.
+    pub async fn get_user_plannings(&self) -> Result<Vec<UserPlanning>> {
+        let _navigation = self.navigation.lock().await;
+        self.user_plannings().await
+    }
+
+    /// Get the plannings of the user, see [`Aurion::get_user_plannings`].
+    async fn user_plannings(&self) -> Result<Vec<UserPlanning>> {
+        let schooling_id = self.menu.read().unwrap().schooling_id().to_string();
+        self.load_nodes([schooling_id.clone()]).await?;
+        let children = self
+            .menu
+            .read()
+            .unwrap()
+            .get_menu_node(schooling_id)
+            .map(|node| node.read().unwrap().get_children().to_vec())
+            .unwrap_or_default();
+
+        // "Planning" is stripped from the labels, leaving "Mon" or "My"
+        let user_planning_id = self.menu.read().unwrap().user_planning_id().to_string();
+        let mut plannings = Vec::new();
+        for node in &children {
+            let menu_id = match self.menu.read().unwrap().menu_id_of(node) {
+                Some(menu_id) => menu_id.to_string(),
+                None => continue,
+            };
+            let node = node.read().unwrap();
+            let first_word = node.name.split_whitespace().next().unwrap_or_default();
+            let is_user_planning = menu_id == user_planning_id
+                || ["mon", "my"].contains(&first_word.to_lowercase().as_str());
+            if node.is_leaf() && is_user_planning {
+                plannings.push(UserPlanning {
+                    menu_id,
+                    id: node.id.clone(),
+                    name: node.name.clone(),
+                });
+            }
+        }
+
+        // Keep the configured user planning first
+        plannings.sort_by_key(|planning| planning.menu_id != user_planning_id);
+
+        Ok(plannings)
+    }
+
+    /// Select the user planning fetched by `get_user_schedule`, among the
+    /// ones listed by `get_user_plannings`, by index or by name.
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// # async fn run() -> anyhow::Result<()> {
+    /// #     let mut aurion = Aurion::new(
+    /// #         275805,
+    /// #         "submenu_291906",
+    /// #         "1_3",
+    /// #         "submenu_299102",
+    /// #         "https://web.isen-ouest.fr/webAurion/",
+    /// #     );
+    /// #     aurion.login("username", "password").await?;
+    /// aurion.select_user_planning("ENSTA").await?;
+    /// let schedule = aurion.get_user_schedule(None, None).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn select_user_planning<S: Into<UserPlanningSelector>>(
+        &self,
+        selector: S,
+    ) -> Result<UserPlanning> {
+        let selector = selector.into();
+        let _navigation = self.navigation.lock().await;
+        let plannings = self.user_plannings().await?;
+
+        match selector.find(&plannings) {
+            Some(planning) => {
+                debug!("Selected user planning {}", planning.menu_id);
+                self.menu
+                    .write()
+                    .unwrap()
+                    .set_user_planning_id(planning.menu_id.clone());
+                Ok(planning.clone())
+            }
+            None => {
+                let message = format!("User planning {:?} not found", selector);
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        }
+    }
 
     /// Get the user's schedule.
     /// The schedule is returned as a vector of `Value`s.
+    ///
+    /// Ranges whose end isn't after their start, or spanning more than two
+    /// years, are refused with [`AurionError::InvalidDateRange`] before
+    /// reaching the server.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::{Aurion, AurionError};
+    /// use chrono::{Duration, Utc};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut aurion = Aurion::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// );
+    ///
+    /// let now = Utc::now();
+    /// let error = aurion
+    ///     .get_user_schedule(Some(now), Some(now - Duration::days(1)))
+    ///     .await
+    ///     .unwrap_err();
+    /// assert!(matches!(
+    ///     error.downcast_ref::<AurionError>(),
+    ///     Some(AurionError::InvalidDateRange { .. })
+    /// ));
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
     pub async fn get_user_schedule(
-        &mut self,
+        &self,
         start: Option<DateTime<Utc>>,
         end: Option<DateTime<Utc>>,
     ) -> Result<Vec<Event>> {
+        self.fetch_user_schedule(start, end, true).await
+    }
+
+    /// Get the user's schedule, fetched in chunks if `chunked`.
+    async fn fetch_user_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        chunked: bool,
+    ) -> Result<Vec<Event>> {
+        // The selected user planning can't change until the schedule is
+        // fetched
+        let _navigation = self.navigation.lock().await;
+
+        // Check if the schedule is cached
+        let user_planning_id = self.menu.read().unwrap().user_planning_id().to_string();
+        let start = start.unwrap_or(self.start);
+        let end = end.unwrap_or(self.end);
+        validate_range(start, end)?;
+        if let Some(schedule) = self.cache.get(&user_planning_id, start, end) {
+            return Ok(schedule);
+        }
+
+        self.prepare_user_schedule().await?;
+
+        // Send the request to get the user's schedule
+        let schedule = self.get_schedule(Some(start), Some(end), chunked).await?;
+        self.cache.insert(&user_planning_id, start, end, &schedule);
+
+        Ok(schedule)
+    }
+
+    /// Get the schedule described by the given request, see
+    /// [`ScheduleRequest`].
+    ///
+    /// Ranges of days are converted with the school timezone, see
+    /// [`AurionBuilder::timezone`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// use aurion_rs::event::EventKind;
+    /// use aurion_rs::schedule::ScheduleRequest;
+    /// use chrono::{Duration, Utc};
+    ///
+    /// # async fn run(mut aurion: Aurion) -> anyhow::Result<()> {
+    /// let request = ScheduleRequest::new()
+    ///     .start(Utc::now())
+    ///     .end(Utc::now() + Duration::weeks(8))
+    ///     .kinds([EventKind::Exam]);
+    /// let exams = aurion.fetch_schedule(&request).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_schedule(&self, request: &ScheduleRequest) -> Result<Vec<Event>> {
+        let (start, end) = request.range.resolve(self.timezone);
+        let chunked = request.chunked;
+        let events = match &request.group {
+            Some(group) => {
+                self.fetch_group_schedule(group, start, end, chunked)
+                    .await?
+            }
+            None => self.fetch_user_schedule(start, end, chunked).await?,
+        };
+
+        Ok(request.filter(events))
+    }
+
+    /// Stream the user's schedule, yielding the events of each chunk as soon
+    /// as it is fetched and parsed, see [`AurionBuilder::schedule_chunk_size`].
+    ///
+    /// This allows rendering long ranges progressively instead of waiting
+    /// for the whole school year. Events spanning two chunks are yielded
+    /// once, deduplicated like [`Aurion::get_user_schedule`] does, and the
+    /// stream ends after the first error. The streamed events
+    /// are not cached. The session is held until the stream ends or is
+    /// dropped, so other operations on the session wait for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn run(mut aurion: Aurion) -> anyhow::Result<()> {
+    /// let mut events = Box::pin(aurion.stream_schedule(None, None));
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event?.subject);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn stream_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Stream<Item = Result<Event>> + '_ {
+        let start = start.unwrap_or(self.start);
+        let end = end.unwrap_or(self.end);
+        let chunks = match validate_range(start, end) {
+            Ok(()) => VecDeque::from(self.schedule_chunks(start, end)),
+            Err(e) => return stream::iter(vec![Err(e)]).left_stream(),
+        };
+
+        // The schedule is opened with the first chunk
+        let state = ScheduleStreamState {
+            aurion: self,
+            navigation: None,
+            schedule: None,
+            dedup: chunks.len() > 1,
+            chunks,
+            yielded: Vec::new(),
+        };
+        stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+            let chunk = state.chunks.pop_front()?;
+
+            let (schedule_form_id, view_state) = match state.schedule.take() {
+                Some(schedule) => schedule,
+                None => {
+                    // Hold the session until the last chunk is fetched
+                    state.navigation = Some(state.aurion.navigation.lock().await);
+                    let opened = match state.aurion.prepare_user_schedule().await {
+                        Ok(()) => state.aurion.open_schedule().await,
+                        Err(e) => Err(e),
+                    };
+                    match opened {
+                        Ok(schedule) => schedule,
+                        Err(e) => return Some((vec![Err(e)], None)),
+                    }
+                }
+            };
+
+            let events = match state
+                .aurion
+                .get_schedule_chunk(schedule_form_id, &view_state, &chunk)
+                .await
+            {
+                Ok(events) => events,
+                Err(e) => return Some((vec![Err(e)], None)),
+            };
+
+            // Events spanning two chunks are listed in both, skip the ones
+            // already yielded like `dedup` does for the whole schedule
+            let mut new_events = Vec::with_capacity(events.len());
+            for event in events {
+                if state.dedup {
+                    if state
+                        .yielded
+                        .iter()
+                        .any(|other| is_duplicate(&event, other))
+                    {
+                        continue;
+                    }
+                    state.yielded.push(event.clone());
+                }
+                new_events.push(Ok(event));
+            }
+            state.schedule = Some((schedule_form_id, view_state));
+            Some((new_events, Some(state)))
+        })
+        .flat_map(stream::iter)
+        .right_stream()
+    }
+
+    /// Navigate to the user planning, so that the planning page serves the
+    /// user's schedule.
+    async fn prepare_user_schedule(&self) -> Result<()> {
         // Load the schooling menu node if it is not loaded
-        let schooling_id = self.menu.schooling_id().to_string();
-        if !self.menu.is_node_loaded(schooling_id.clone()) {
+        let schooling_id = self.menu.read().unwrap().schooling_id().to_string();
+        if !self
+            .menu
+            .read()
+            .unwrap()
+            .is_node_loaded(schooling_id.clone())
+        {
             debug!("Loading schooling menu node: {}", schooling_id.clone());
-            self.load_menu_nodes([schooling_id.clone()]).await.unwrap();
+            self.load_nodes([schooling_id.clone()]).await?;
         }
 
         // Get the user planning node
-        let user_planning_node = self
-            .menu
-            .get_menu_node(self.menu.user_planning_id())
-            .unwrap();
+        let user_planning_id = self.menu.read().unwrap().user_planning_id().to_string();
+        let user_planning_node = match self.menu.read().unwrap().get_menu_node(&user_planning_id) {
+            Some(node) => node,
+            None => {
+                let error = AurionError::InvalidMenuId {
+                    name: "user_planning_id".to_string(),
+                    id: user_planning_id,
+                };
+                error!("{}", error);
+                return Err(Error::new(error));
+            }
+        };
 
         // Send the request to prepare to get the user's schedule
         trace!("Preparing to get user schedule");
-        let payload = self.default_parameters(user_planning_node.borrow().id.clone());
+        let payload = self.default_parameters(user_planning_node.read().unwrap().id.clone());
         let response = self
-            .client
-            .post(self.pages.main_menu_url())
-            .form(&payload)
-            .send()
+            .send(Request::post(self.pages.main_menu_url(), payload))
             .await?;
         trace!("Prepared to get user schedule");
 
         // Parse the response
-        let headers = response.headers().clone();
+        let headers = response.headers;
 
         // Check if the response is valid
-        if !headers.clone().contains_key("location") {
-            let message = format!("Response to prepare to get user schedule is not valid");
+        if !headers.contains_key("location") {
+            let message = "Response to prepare to get user schedule is not valid".to_string();
             error!("{}", message);
             return Err(Error::msg(message));
         }
 
-        // Send the request to get the user's schedule
-        let schedule = self.get_schedule(start, end).await.unwrap();
+        Ok(())
+    }
 
-        Ok(schedule)
+    /// Get the user's schedule of the given day, in the school timezone.
+    pub async fn get_user_schedule_day(&self, date: NaiveDate) -> Result<Vec<Event>> {
+        let (start, end) = day_range(date, self.timezone);
+        self.get_user_schedule(Some(start), Some(end)).await
+    }
+
+    /// Get the user's schedule of today, in the school timezone.
+    pub async fn get_user_schedule_today(&self) -> Result<Vec<Event>> {
+        self.get_user_schedule_day(today(self.timezone)).await
+    }
+
+    /// Get the user's schedule of a week, from Monday to Sunday in the school
+    /// timezone.
+    ///
+    /// The week is relative to the current one: `0` is the current week, `1`
+    /// the next one and `-1` the previous one.
+    pub async fn get_user_schedule_week(&self, week_offset: i64) -> Result<Vec<Event>> {
+        let date = match shift_weeks(today(self.timezone), week_offset) {
+            Some(date) => date,
+            None => {
+                let message = format!("Week offset {} is out of range", week_offset);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        let (start, end) = week_range(date, self.timezone);
+        self.get_user_schedule(Some(start), Some(end)).await
+    }
+
+    /// Watch the user's schedule, notifying the webhook of its changes.
+    ///
+    /// The schedule from now to `range` later is fetched every `interval`,
+    /// and compared to the previous fetch with [`diff`]. When events were
+    /// added, moved or cancelled, the changes are posted to the webhook.
+    /// Events that left the range since the previous fetch are not reported
+    /// as cancelled.
+    ///
+    /// This only returns if the first fetch fails: later failures to fetch
+    /// the schedule or to notify the webhook are logged and retried at the
+    /// next interval.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// use std::time::Duration;
+    ///
+    /// use aurion_rs::watch::Webhook;
+    ///
+    /// # async fn run(mut aurion: Aurion) -> anyhow::Result<()> {
+    /// let webhook = Webhook::new("https://example.com/hooks/aurion")?;
+    /// aurion
+    ///     .watch_schedule(
+    ///         Duration::from_secs(15 * 60),
+    ///         Duration::from_secs(14 * 24 * 60 * 60),
+    ///         &webhook,
+    ///     )
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn watch_schedule(
+        &self,
+        interval: Duration,
+        range: Duration,
+        webhook: &Webhook,
+    ) -> Result<()> {
+        let range = chrono::Duration::from_std(range)?;
+        let window = |now: DateTime<Utc>| DateRange::new(now, now + range);
+
+        let first = window(Utc::now());
+        let mut events = self
+            .get_user_schedule(Some(first.start), Some(first.end))
+            .await?;
+
+        loop {
+            tokio::time::sleep(interval).await;
+
+            let current = window(Utc::now());
+            let new_events = match self
+                .get_user_schedule(Some(current.start), Some(current.end))
+                .await
+            {
+                Ok(new_events) => new_events,
+                Err(e) => {
+                    warn!("Failed to fetch the watched schedule: {}", e);
+                    continue;
+                }
+            };
+
+            // Ignore the events that left the range since the previous fetch
+            let old_events = events
+                .iter()
+                .filter(|event| event.end > current.start)
+                .cloned()
+                .collect::<Vec<Event>>();
+            let changes = diff(&old_events, &new_events);
+            events = new_events;
+
+            if changes.is_empty() {
+                trace!("No change in the watched schedule");
+                continue;
+            }
+
+            info!("The watched schedule changed");
+            if let Err(e) = webhook.notify(&current, changes).await {
+                warn!("Failed to notify the webhook: {}", e);
+            }
+        }
+    }
+}
+
+/// Check that a schedule date range can be fetched.
+///
+/// The end must be after the start, and the range can't span more than
+/// [`MAX_SCHEDULE_SPAN_DAYS`], as Aurion answers such requests with confusing
+/// or enormous responses.
+fn validate_range(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<()> {
+    let reason = if end <= start {
+        "the end is not after the start".to_string()
+    } else if end - start > chrono::Duration::days(MAX_SCHEDULE_SPAN_DAYS) {
+        format!(
+            "the range spans {} days, more than the maximum of {} days",
+            (end - start).num_days(),
+            MAX_SCHEDULE_SPAN_DAYS
+        )
+    } else {
+        return Ok(());
+    };
+
+    let error = AurionError::InvalidDateRange { start, end, reason };
+    error!("{}", error);
+    Err(Error::new(error))
+}
+
+/// Get the url of the login page of the CAS server at `cas_url` for the
+/// given service.
+fn cas_login_url(cas_url: &Url, service_url: &Url) -> Result<Url> {
+    // Keep the last segment of the CAS url, such as "cas" in
+    // "https://sso.example/cas"
+    let mut base = cas_url.clone();
+    if !base.path().ends_with('/') {
+        base.set_path(&format!("{}/", base.path()));
+    }
+
+    let mut login_url = base.join("login")?;
+    login_url
+        .query_pairs_mut()
+        .append_pair("service", service_url.as_str());
+    Ok(login_url)
+}
+
+/// Get the url of the logout page of the CAS server at `cas_url`.
+fn cas_logout_url(cas_url: &Url) -> Result<Url> {
+    let mut base = cas_url.clone();
+    if !base.path().ends_with('/') {
+        base.set_path(&format!("{}/", base.path()));
+    }
+
+    Ok(base.join("logout")?)
+}
+
+/// Get the service url, carrying a CAS service ticket, that a response to
+/// `url` redirects to.
+fn service_ticket_url(url: &Url, response: &Response) -> Result<Option<Url>> {
+    let location = match response.location() {
+        Some(location) => url.join(location)?,
+        None => return Ok(None),
+    };
+
+    let has_ticket = location.query_pairs().any(|(name, _)| name == "ticket");
+    Ok(has_ticket.then_some(location))
+}
+
+/// Create the error of an account without groups planning branch.
+fn no_group_plannings() -> Error {
+    let error = AurionError::NoGroupPlannings;
+    error!("{}", error);
+    Error::new(error)
+}
+
+/// Create the request submitting the form of the page at `url`, whose html
+/// content is `text`, with the button with the given id.
+fn button_request(url: &Url, text: &str, button_id: &str) -> Request {
+    let payload = json!({
+        "form": "form",
+        button_id: button_id,
+        "javax.faces.ViewState": get_view_state(text),
+    });
+
+    Request::post(url.clone(), payload)
+}
+
+/// The planning page opened last, whose events can be selected to get their
+/// details.
+struct ScheduleView {
+    form_id: u8,
+    view_state: String,
+    served: Option<DateRange>,
+    chunks: HashMap<EventId, DateRange>,
+}
+
+/// The state of a schedule stream between two chunks.
+struct ScheduleStreamState<'a, T: Transport> {
+    aurion: &'a Aurion<T>,
+    navigation: Option<tokio::sync::MutexGuard<'a, ()>>,
+    schedule: Option<(u8, Option<String>)>,
+    chunks: VecDeque<DateRange>,
+    dedup: bool,
+    yielded: Vec<Event>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+    use crate::test_support::{aurion, event, FakeTransport};
+
+    #[tokio::test]
+    async fn gets_the_details_of_events_of_any_chunk() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let aurion = aurion(FakeTransport::new(vec![
+            event("1", start + Duration::days(1)),
+            event("2", start + Duration::days(80)),
+        ]));
+
+        // The range is fetched in three chunks
+        let schedule = aurion
+            .get_user_schedule(Some(start), Some(start + Duration::days(90)))
+            .await
+            .unwrap();
+        assert_eq!(schedule.len(), 2);
+
+        for event in &schedule {
+            let details = aurion.get_event_details(&event.id).await.unwrap();
+            assert_eq!(details.id, event.id);
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_the_events_of_the_whole_schedule() {
+        use futures_util::StreamExt;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let boundary = start + Duration::days(30) - Duration::hours(1);
+        let aurion = aurion(FakeTransport::new(vec![
+            event("1", start + Duration::days(1)),
+            // Listed in the first two chunks, once with another id
+            event("2", boundary),
+            event("3", boundary),
+        ]));
+        let end = Some(start + Duration::days(90));
+
+        let streamed = aurion
+            .stream_schedule(Some(start), end)
+            .map(|event| event.unwrap().id)
+            .collect::<Vec<EventId>>()
+            .await;
+        let schedule = aurion.get_user_schedule(Some(start), end).await.unwrap();
+        assert_eq!(streamed.len(), 2);
+        assert_eq!(
+            streamed,
+            schedule
+                .into_iter()
+                .map(|event| event.id)
+                .collect::<Vec<_>>()
+        );
     }
 }
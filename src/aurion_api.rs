@@ -0,0 +1,402 @@
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use anyhow::Result;
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::aurion::Aurion;
+use crate::document::{Document, ReportCard};
+use crate::event::{Event, EventDetails, EventId, Exam};
+use crate::profile::Profile;
+use crate::read_only_aurion::ReadOnlyAurion;
+use crate::roster::Student;
+use crate::schedule::{ClassGroup, ScheduleRequest, Teacher};
+use crate::stages::{Internship, InternshipOffer};
+use crate::transport::Transport;
+
+/// The fetching surface of Aurion, implemented by [`Aurion`],
+/// [`ReadOnlyAurion`] and [`MockAurion`](crate::MockAurion).
+///
+/// Code depending on this trait rather than on [`Aurion`] can be tested
+/// with a [`MockAurion`](crate::MockAurion), without network. The returned
+/// futures are `Send`, so that they can be spawned on a multi-threaded
+/// runtime.
+///
+/// The methods behave like their [`Aurion`] counterparts.
+pub trait AurionApi {
+    /// Get the class groups designated by class_group_id.
+    fn get_class_groups(
+        &self,
+        class_group_id: &str,
+    ) -> impl Future<Output = Result<Vec<ClassGroup>>> + Send;
+
+    /// Get the schedule of a class group.
+    fn get_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the merged schedule of multiple class groups.
+    fn get_combined_schedule(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the schedules of multiple class groups.
+    fn get_group_schedules(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<HashMap<ClassGroup, Vec<Event>>>> + Send;
+
+    /// Get the schedule of a room.
+    fn get_room_schedule(
+        &self,
+        room_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the teachers whose planning can be fetched.
+    fn get_teachers(&self) -> impl Future<Output = Result<Vec<Teacher>>> + Send;
+
+    /// Get the schedule of a teacher.
+    fn get_teacher_schedule(
+        &self,
+        teacher_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the details of an event of the last fetched schedule.
+    fn get_event_details(
+        &self,
+        event_id: &EventId,
+    ) -> impl Future<Output = Result<EventDetails>> + Send;
+
+    /// Get the exams listed in the exams page.
+    fn get_exams(&self) -> impl Future<Output = Result<Vec<Exam>>> + Send;
+
+    /// Get the personal information of the logged in user.
+    fn get_profile(&self) -> impl Future<Output = Result<Profile>> + Send;
+
+    /// Get the students of a class group, listed in the trombinoscope.
+    fn get_roster(&self, group_id: u32) -> impl Future<Output = Result<Vec<Student>>> + Send;
+
+    /// Get the administrative documents that can be downloaded.
+    fn list_documents(&self) -> impl Future<Output = Result<Vec<Document>>> + Send;
+
+    /// Download the administrative document with the given id.
+    fn download_document(&self, document_id: &str) -> impl Future<Output = Result<Bytes>> + Send;
+
+    /// Get the grade bulletins published for the user.
+    fn list_report_cards(&self) -> impl Future<Output = Result<Vec<ReportCard>>> + Send;
+
+    /// Download the grade bulletin of the given semester.
+    fn download_report_card(&self, semester: &str) -> impl Future<Output = Result<Bytes>> + Send;
+
+    /// Download the school certificate of the user.
+    fn download_school_certificate(&self) -> impl Future<Output = Result<Bytes>> + Send;
+
+    /// Get the internship offers published in Aurion.
+    fn get_internship_offers(&self) -> impl Future<Output = Result<Vec<InternshipOffer>>> + Send;
+
+    /// Get the internship offer with the given id.
+    fn get_internship_offer(
+        &self,
+        offer_id: &str,
+    ) -> impl Future<Output = Result<InternshipOffer>> + Send;
+
+    /// Get the internships of the user.
+    fn get_internships(&self) -> impl Future<Output = Result<Vec<Internship>>> + Send;
+
+    /// Get the user's schedule.
+    fn get_user_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the user's schedule of the given day.
+    fn get_user_schedule_day(
+        &self,
+        date: NaiveDate,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the user's schedule of today.
+    fn get_user_schedule_today(&self) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the user's schedule of a week, relative to the current one.
+    fn get_user_schedule_week(
+        &self,
+        week_offset: i64,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+
+    /// Get the schedule described by the given request.
+    fn fetch_schedule(
+        &self,
+        request: &ScheduleRequest,
+    ) -> impl Future<Output = Result<Vec<Event>>> + Send;
+}
+
+impl<T: Transport> AurionApi for Aurion<T> {
+    async fn get_class_groups(&self, class_group_id: &str) -> Result<Vec<ClassGroup>> {
+        Aurion::get_class_groups(self, class_group_id).await
+    }
+
+    async fn get_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        Aurion::get_group_schedule(self, group, start, end).await
+    }
+
+    async fn get_combined_schedule(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        Aurion::get_combined_schedule(self, groups, start, end).await
+    }
+
+    async fn get_group_schedules(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<HashMap<ClassGroup, Vec<Event>>> {
+        Aurion::get_group_schedules(self, groups, start, end).await
+    }
+
+    async fn get_room_schedule(
+        &self,
+        room_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        Aurion::get_room_schedule(self, room_id, start, end).await
+    }
+
+    async fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        Aurion::get_teachers(self).await
+    }
+
+    async fn get_teacher_schedule(
+        &self,
+        teacher_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        Aurion::get_teacher_schedule(self, teacher_id, start, end).await
+    }
+
+    async fn get_event_details(&self, event_id: &EventId) -> Result<EventDetails> {
+        Aurion::get_event_details(self, event_id).await
+    }
+
+    async fn get_exams(&self) -> Result<Vec<Exam>> {
+        Aurion::get_exams(self).await
+    }
+
+    async fn get_profile(&self) -> Result<Profile> {
+        Aurion::get_profile(self).await
+    }
+
+    async fn get_roster(&self, group_id: u32) -> Result<Vec<Student>> {
+        Aurion::get_roster(self, group_id).await
+    }
+
+    async fn list_documents(&self) -> Result<Vec<Document>> {
+        Aurion::list_documents(self).await
+    }
+
+    async fn download_document(&self, document_id: &str) -> Result<Bytes> {
+        Aurion::download_document(self, document_id).await
+    }
+
+    async fn list_report_cards(&self) -> Result<Vec<ReportCard>> {
+        Aurion::list_report_cards(self).await
+    }
+
+    async fn download_report_card(&self, semester: &str) -> Result<Bytes> {
+        Aurion::download_report_card(self, semester).await
+    }
+
+    async fn download_school_certificate(&self) -> Result<Bytes> {
+        Aurion::download_school_certificate(self).await
+    }
+
+    async fn get_internship_offers(&self) -> Result<Vec<InternshipOffer>> {
+        Aurion::get_internship_offers(self).await
+    }
+
+    async fn get_internship_offer(&self, offer_id: &str) -> Result<InternshipOffer> {
+        Aurion::get_internship_offer(self, offer_id).await
+    }
+
+    async fn get_internships(&self) -> Result<Vec<Internship>> {
+        Aurion::get_internships(self).await
+    }
+
+    async fn get_user_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        Aurion::get_user_schedule(self, start, end).await
+    }
+
+    async fn get_user_schedule_day(&self, date: NaiveDate) -> Result<Vec<Event>> {
+        Aurion::get_user_schedule_day(self, date).await
+    }
+
+    async fn get_user_schedule_today(&self) -> Result<Vec<Event>> {
+        Aurion::get_user_schedule_today(self).await
+    }
+
+    async fn get_user_schedule_week(&self, week_offset: i64) -> Result<Vec<Event>> {
+        Aurion::get_user_schedule_week(self, week_offset).await
+    }
+
+    async fn fetch_schedule(&self, request: &ScheduleRequest) -> Result<Vec<Event>> {
+        Aurion::fetch_schedule(self, request).await
+    }
+}
+
+impl<T: Transport> AurionApi for ReadOnlyAurion<T> {
+    async fn get_class_groups(&self, class_group_id: &str) -> Result<Vec<ClassGroup>> {
+        ReadOnlyAurion::get_class_groups(self, class_group_id).await
+    }
+
+    async fn get_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_group_schedule(self, group, start, end).await
+    }
+
+    async fn get_combined_schedule(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_combined_schedule(self, groups, start, end).await
+    }
+
+    async fn get_group_schedules(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<HashMap<ClassGroup, Vec<Event>>> {
+        ReadOnlyAurion::get_group_schedules(self, groups, start, end).await
+    }
+
+    async fn get_room_schedule(
+        &self,
+        room_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_room_schedule(self, room_id, start, end).await
+    }
+
+    async fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        ReadOnlyAurion::get_teachers(self).await
+    }
+
+    async fn get_teacher_schedule(
+        &self,
+        teacher_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_teacher_schedule(self, teacher_id, start, end).await
+    }
+
+    async fn get_event_details(&self, event_id: &EventId) -> Result<EventDetails> {
+        ReadOnlyAurion::get_event_details(self, event_id).await
+    }
+
+    async fn get_exams(&self) -> Result<Vec<Exam>> {
+        ReadOnlyAurion::get_exams(self).await
+    }
+
+    async fn get_profile(&self) -> Result<Profile> {
+        ReadOnlyAurion::get_profile(self).await
+    }
+
+    async fn get_roster(&self, group_id: u32) -> Result<Vec<Student>> {
+        ReadOnlyAurion::get_roster(self, group_id).await
+    }
+
+    async fn list_documents(&self) -> Result<Vec<Document>> {
+        ReadOnlyAurion::list_documents(self).await
+    }
+
+    async fn download_document(&self, document_id: &str) -> Result<Bytes> {
+        ReadOnlyAurion::download_document(self, document_id).await
+    }
+
+    async fn list_report_cards(&self) -> Result<Vec<ReportCard>> {
+        ReadOnlyAurion::list_report_cards(self).await
+    }
+
+    async fn download_report_card(&self, semester: &str) -> Result<Bytes> {
+        ReadOnlyAurion::download_report_card(self, semester).await
+    }
+
+    async fn download_school_certificate(&self) -> Result<Bytes> {
+        ReadOnlyAurion::download_school_certificate(self).await
+    }
+
+    async fn get_internship_offers(&self) -> Result<Vec<InternshipOffer>> {
+        ReadOnlyAurion::get_internship_offers(self).await
+    }
+
+    async fn get_internship_offer(&self, offer_id: &str) -> Result<InternshipOffer> {
+        ReadOnlyAurion::get_internship_offer(self, offer_id).await
+    }
+
+    async fn get_internships(&self) -> Result<Vec<Internship>> {
+        ReadOnlyAurion::get_internships(self).await
+    }
+
+    async fn get_user_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_user_schedule(self, start, end).await
+    }
+
+    async fn get_user_schedule_day(&self, date: NaiveDate) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_user_schedule_day(self, date).await
+    }
+
+    async fn get_user_schedule_today(&self) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_user_schedule_today(self).await
+    }
+
+    async fn get_user_schedule_week(&self, week_offset: i64) -> Result<Vec<Event>> {
+        ReadOnlyAurion::get_user_schedule_week(self, week_offset).await
+    }
+
+    async fn fetch_schedule(&self, request: &ScheduleRequest) -> Result<Vec<Event>> {
+        ReadOnlyAurion::fetch_schedule(self, request).await
+    }
+}
@@ -0,0 +1,494 @@
+#![deny(missing_docs)]
+
+use std::time::Duration;
+
+use chrono_tz::Tz;
+use http::{HeaderName, HeaderValue};
+use reqwest::Client;
+
+use crate::aurion::Aurion;
+use crate::auth_method::AuthMethod;
+use crate::event::KindMapping;
+use crate::format::Translations;
+use crate::transport::{
+    ClientOptions, ProxyConfig, RequestHook, ReqwestTransport, RetryPolicy, Timeouts, Transport,
+};
+use crate::two_factor::TwoFactor;
+
+/// The default number of days of the schedule chunks.
+const SCHEDULE_CHUNK_DAYS: u64 = 31;
+
+/// A builder to configure an [`Aurion`] instance.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::transport::RetryPolicy;
+/// use aurion_rs::AurionBuilder;
+///
+/// let aurion = AurionBuilder::new(
+///     275805,
+///     "submenu_291906",
+///     "1_3",
+///     "submenu_299102",
+///     "https://web.isen-ouest.fr/webAurion/",
+/// )
+/// .retry_policy(RetryPolicy {
+///     max_attempts: 5,
+///     ..RetryPolicy::default()
+/// })
+/// .build();
+/// ```
+pub struct AurionBuilder<T: Transport = ReqwestTransport> {
+    pub(crate) language_code: u32,
+    pub(crate) schooling_id: String,
+    pub(crate) user_planning_id: String,
+    pub(crate) groups_planning_id: String,
+    pub(crate) service_url: String,
+    pub(crate) auth_method: AuthMethod,
+    pub(crate) two_factor: Option<TwoFactor>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) timeouts: Timeouts,
+    pub(crate) schedule_chunk_size: Option<Duration>,
+    pub(crate) cache_ttl: Option<Duration>,
+    pub(crate) details_cache_ttl: Option<Duration>,
+    pub(crate) timezone: Tz,
+    pub(crate) dst_correction: bool,
+    pub(crate) record_timings: bool,
+    pub(crate) pin_host: bool,
+    pub(crate) validate_menu_ids: bool,
+    pub(crate) translations: Translations,
+    pub(crate) kind_mapping: KindMapping,
+    pub(crate) hooks: Vec<Box<dyn RequestHook>>,
+    pub(crate) client_options: ClientOptions,
+    pub(crate) transport: T,
+}
+
+impl AurionBuilder {
+    /// Create a new builder with the required Aurion menu ids and service url.
+    pub fn new<S: Into<String>, U: Into<String>, G: Into<String>, V: Into<String>>(
+        language_code: u32,
+        schooling_id: S,
+        user_planning_id: U,
+        groups_planning_id: G,
+        service_url: V,
+    ) -> Self {
+        Self {
+            language_code,
+            schooling_id: schooling_id.into(),
+            user_planning_id: user_planning_id.into(),
+            groups_planning_id: groups_planning_id.into(),
+            service_url: service_url.into(),
+            auth_method: AuthMethod::default(),
+            two_factor: None,
+            retry_policy: RetryPolicy::default(),
+            timeouts: Timeouts::default(),
+            schedule_chunk_size: Some(Duration::from_secs(SCHEDULE_CHUNK_DAYS * 24 * 60 * 60)),
+            cache_ttl: None,
+            details_cache_ttl: None,
+            timezone: chrono_tz::Europe::Paris,
+            dst_correction: false,
+            record_timings: false,
+            pin_host: false,
+            validate_menu_ids: false,
+            translations: Translations::new(),
+            kind_mapping: KindMapping::default(),
+            hooks: Vec::new(),
+            client_options: ClientOptions::default(),
+            transport: ReqwestTransport::new(),
+        }
+    }
+}
+
+impl AurionBuilder {
+    /// Create a new builder without the Aurion menu ids, which are then
+    /// discovered after login, see [`Aurion::discover_menu_ids`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::AurionBuilder;
+    ///
+    /// let aurion = AurionBuilder::discovering(275805, "https://web.isen-ouest.fr/webAurion/")
+    ///     .build();
+    /// assert!(!aurion.menu().has_menu_ids());
+    /// ```
+    pub fn discovering<V: Into<String>>(language_code: u32, service_url: V) -> Self {
+        Self::new(language_code, "", "", "", service_url)
+    }
+
+    /// Send the requests through the given HTTP or SOCKS5 proxy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::transport::ProxyConfig;
+    /// use aurion_rs::AurionBuilder;
+    ///
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// )
+    /// .proxy(ProxyConfig::new("http://proxy.school.example:3128").unwrap())
+    /// .build();
+    /// ```
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.client_options.proxy = Some(proxy);
+        self.rebuild_transport()
+    }
+
+    /// Send the given User-Agent header instead of the reqwest default one,
+    /// for deployments filtering unusual user agents.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::AurionBuilder;
+    /// use http::header::ACCEPT_LANGUAGE;
+    /// use http::HeaderValue;
+    ///
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// )
+    /// .user_agent(HeaderValue::from_static("Mozilla/5.0 (X11; Linux x86_64)"))
+    /// .header(ACCEPT_LANGUAGE, HeaderValue::from_static("fr-FR"))
+    /// .build();
+    /// ```
+    pub fn user_agent(mut self, user_agent: HeaderValue) -> Self {
+        self.client_options.user_agent = Some(user_agent);
+        self.rebuild_transport()
+    }
+
+    /// Send the given header with every request, replacing any header of
+    /// the same name previously set.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.client_options.headers.insert(name, value);
+        self.rebuild_transport()
+    }
+
+    /// Send the requests with the given client, for example to share its
+    /// connection pool with the rest of the application.
+    ///
+    /// The client must not follow redirections nor keep cookies, see
+    /// [`ReqwestTransport::from_client`]. The options of the default client,
+    /// such as [`proxy`](Self::proxy) or [`user_agent`](Self::user_agent),
+    /// replace the given client when set afterwards: they must be configured
+    /// on the client instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::AurionBuilder;
+    /// use reqwest::redirect::Policy;
+    /// use reqwest::Client;
+    ///
+    /// let client = Client::builder().redirect(Policy::none()).build().unwrap();
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// )
+    /// .with_client(client)
+    /// .build();
+    /// ```
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.transport = ReqwestTransport::from_client(client);
+        self
+    }
+
+    /// Rebuild the transport with the current client options.
+    fn rebuild_transport(mut self) -> Self {
+        self.transport = ReqwestTransport::with_options(&self.client_options);
+        self
+    }
+}
+
+impl<T: Transport> AurionBuilder<T> {
+    /// Use the given transport to send the requests.
+    pub fn transport<U: Transport>(self, transport: U) -> AurionBuilder<U> {
+        AurionBuilder {
+            language_code: self.language_code,
+            schooling_id: self.schooling_id,
+            user_planning_id: self.user_planning_id,
+            groups_planning_id: self.groups_planning_id,
+            service_url: self.service_url,
+            auth_method: self.auth_method,
+            two_factor: self.two_factor,
+            retry_policy: self.retry_policy,
+            timeouts: self.timeouts,
+            schedule_chunk_size: self.schedule_chunk_size,
+            cache_ttl: self.cache_ttl,
+            details_cache_ttl: self.details_cache_ttl,
+            timezone: self.timezone,
+            dst_correction: self.dst_correction,
+            record_timings: self.record_timings,
+            pin_host: self.pin_host,
+            validate_menu_ids: self.validate_menu_ids,
+            translations: self.translations,
+            kind_mapping: self.kind_mapping,
+            hooks: self.hooks,
+            client_options: self.client_options,
+            transport,
+        }
+    }
+
+    /// Set how the user is authenticated on login, see [`AuthMethod`].
+    ///
+    /// Defaults to [`AuthMethod::Form`], the built-in login form of Aurion.
+    pub fn auth_method(mut self, auth_method: AuthMethod) -> Self {
+        self.auth_method = auth_method;
+        self
+    }
+
+    /// Answer the two-factor challenge following the credentials on login
+    /// with the code of the given [`TwoFactor`].
+    ///
+    /// Without it, which is the default, logging in to an account requiring
+    /// a one-time code fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use std::sync::Mutex;
+    /// use aurion_rs::transport::{Request, Response, Transport};
+    /// use aurion_rs::{AurionBuilder, TwoFactor};
+    /// use http::header::{LOCATION, SET_COOKIE};
+    /// use http::StatusCode;
+    /// use reqwest::Url;
+    ///
+    /// struct CannedTransport {
+    ///     responses: Mutex<VecDeque<Response>>,
+    /// }
+    ///
+    /// impl Transport for CannedTransport {
+    ///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+    ///         Ok(self.responses.lock().unwrap().pop_front().unwrap())
+    ///     }
+    ///
+    ///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let main_page = Response::new(
+    ///     StatusCode::OK,
+    ///     "<input name=\"javax.faces.ViewState\" value=\"42:0\" />\
+    ///      chargerSousMenu = function() {PrimeFaces.ab({s:\"form:j_idt52\"",
+    /// );
+    /// let transport = CannedTransport {
+    ///     responses: Mutex::new(VecDeque::from([
+    ///         // Two-factor challenge
+    ///         Response::new(
+    ///             StatusCode::OK,
+    ///             "<form action=\"otp\"><input name=\"otp\" autocomplete=\"one-time-code\" />\
+    ///              <input type=\"hidden\" name=\"state\" value=\"s1\" /></form>",
+    ///         )
+    ///         .with_header(SET_COOKIE, "JSESSIONID=0123"),
+    ///         // Accepted code
+    ///         Response::new(StatusCode::FOUND, "").with_header(LOCATION, "https://aurion.example/"),
+    ///         main_page.clone(),
+    ///         // Language switch
+    ///         Response::new(StatusCode::OK, "<partial-response />"),
+    ///         main_page,
+    ///     ])),
+    /// };
+    ///
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://aurion.example/",
+    /// )
+    /// .two_factor(TwoFactor::callback(|| async { Ok("123456".to_string()) }))
+    /// .transport(transport)
+    /// .build();
+    ///
+    /// let token = aurion.login("username", "password").await.unwrap();
+    /// assert_eq!(token, "JSESSIONID=0123");
+    /// # }
+    /// ```
+    pub fn two_factor(mut self, two_factor: TwoFactor) -> Self {
+        self.two_factor = Some(two_factor);
+        self
+    }
+
+    /// Set the policy used to retry failed idempotent requests.
+    ///
+    /// Defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the timeouts of the requests, see [`Timeouts`].
+    ///
+    /// No timeout is set by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use aurion_rs::transport::Timeouts;
+    /// use aurion_rs::AurionBuilder;
+    ///
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// )
+    /// .timeouts(Timeouts {
+    ///     schedule: Some(Duration::from_secs(60)),
+    ///     ..Timeouts::new(Duration::from_secs(15))
+    /// })
+    /// .build();
+    /// ```
+    pub fn timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Set the size of the chunks the schedule date ranges are split into.
+    ///
+    /// Long ranges, such as a whole school year, are fetched one chunk after
+    /// the other, as Aurion may truncate enormous responses. The events of
+    /// the chunks are then concatenated and deduplicated. Defaults to 31
+    /// days, and `None` fetches every range in a single request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use aurion_rs::AurionBuilder;
+    ///
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://web.isen-ouest.fr/webAurion/",
+    /// )
+    /// .schedule_chunk_size(Duration::from_secs(7 * 24 * 60 * 60))
+    /// .build();
+    /// ```
+    pub fn schedule_chunk_size<D: Into<Option<Duration>>>(mut self, size: D) -> Self {
+        self.schedule_chunk_size = size.into();
+        self
+    }
+
+    /// Cache the fetched schedules in memory for the given duration.
+    ///
+    /// Schedules are cached by planning and date range, so fetching the same
+    /// range again before the duration elapsed doesn't hit the server. The
+    /// cache is disabled by default.
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Cache the fetched event details in memory for the given duration.
+    ///
+    /// The details of an event are fetched again once the event is seen
+    /// modified in a fetched schedule, or when invalidated with
+    /// [`Aurion::invalidate_event_details`]. The cache is disabled by default.
+    pub fn details_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.details_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the timezone of the school, used to compute the boundaries of days
+    /// and weeks.
+    ///
+    /// Defaults to `Europe/Paris`.
+    pub fn timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Correct the dates of the events shifted by one hour because of a
+    /// daylight saving time mismatch, see [`check_dst`](crate::event::check_dst).
+    ///
+    /// When disabled, which is the default, the shifted events are only
+    /// reported in the logs.
+    pub fn dst_correction(mut self, dst_correction: bool) -> Self {
+        self.dst_correction = dst_correction;
+        self
+    }
+
+    /// Record the timing of every request sent to Aurion, to be retrieved
+    /// with [`Aurion::take_timings`].
+    ///
+    /// Disabled by default, the timings are only logged.
+    pub fn record_timings(mut self, record_timings: bool) -> Self {
+        self.record_timings = record_timings;
+        self
+    }
+
+    /// Pin the scheme, host and port of the service url.
+    ///
+    /// Requests to another origin, and responses redirecting to another
+    /// origin, for example a phishing or misconfigured SSO host, are then
+    /// refused with [`AurionError::HostMismatch`](crate::AurionError::HostMismatch).
+    /// The origin of the CAS server of [`AuthMethod::Cas`] is allowed as
+    /// well. Disabled by default.
+    pub fn pin_host(mut self, pin_host: bool) -> Self {
+        self.pin_host = pin_host;
+        self
+    }
+
+    /// Check on login that the configured menu ids exist in the sidebar,
+    /// see [`Aurion::validate_menu_ids`].
+    ///
+    /// Disabled by default.
+    pub fn validate_menu_ids(mut self, validate_menu_ids: bool) -> Self {
+        self.validate_menu_ids = validate_menu_ids;
+        self
+    }
+
+    /// Set the translations of the labels written by Aurion, such as the
+    /// event subjects, applied by the renderers given
+    /// [`Aurion::translations`], such as the feed server.
+    ///
+    /// The fetched data is kept as written by Aurion, so that the label
+    /// lookups, such as [`Menu::find_by_name`](crate::menu::Menu::find_by_name),
+    /// and the schedule diffs don't depend on the translations.
+    pub fn translations(mut self, translations: Translations) -> Self {
+        self.translations = translations;
+        self
+    }
+
+    /// Map the class names of the events to their kind with the given
+    /// mapping, for schools using other class names than ISEN.
+    ///
+    /// Defaults to [`KindMapping::default`].
+    pub fn kind_mapping(mut self, kind_mapping: KindMapping) -> Self {
+        self.kind_mapping = kind_mapping;
+        self
+    }
+
+    /// Add a hook invoked around every request sent to Aurion, see
+    /// [`RequestHook`].
+    ///
+    /// Hooks are invoked in the order they were added.
+    pub fn hook<H: RequestHook + 'static>(mut self, hook: H) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Build the Aurion instance.
+    pub fn build(self) -> Aurion<T> {
+        Aurion::from_builder(self)
+    }
+}
@@ -0,0 +1,101 @@
+#![deny(missing_docs)]
+
+use reqwest::Url;
+
+/// How [`Aurion::login`](crate::Aurion::login) authenticates the user.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # use std::sync::Mutex;
+/// use aurion_rs::transport::{Request, Response, Transport};
+/// use aurion_rs::{AuthMethod, AurionBuilder};
+/// use http::header::{LOCATION, SET_COOKIE};
+/// use http::StatusCode;
+/// use reqwest::Url;
+///
+/// struct CannedTransport {
+///     responses: Mutex<VecDeque<Response>>,
+/// }
+///
+/// impl Transport for CannedTransport {
+///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+///         Ok(self.responses.lock().unwrap().pop_front().unwrap())
+///     }
+///
+///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let main_page = Response::new(
+///     StatusCode::OK,
+///     "<input name=\"javax.faces.ViewState\" value=\"42:0\" />\
+///      chargerSousMenu = function() {PrimeFaces.ab({s:\"form:j_idt52\"",
+/// );
+/// let transport = CannedTransport {
+///     responses: Mutex::new(VecDeque::from([
+///         // Aurion redirects to the CAS server
+///         Response::new(StatusCode::FOUND, "").with_header(
+///             LOCATION,
+///             "https://cas.example/cas/login?service=https%3A%2F%2Faurion.example%2F",
+///         ),
+///         // CAS login form
+///         Response::new(
+///             StatusCode::OK,
+///             "<form action=\"login?service=https%3A%2F%2Faurion.example%2F\">\
+///              <input name=\"username\" /><input name=\"password\" type=\"password\" />\
+///              <input type=\"hidden\" name=\"execution\" value=\"e1s1\" />\
+///              </form>",
+///         ),
+///         // CAS grants a service ticket
+///         Response::new(StatusCode::FOUND, "")
+///             .with_header(LOCATION, "https://aurion.example/?ticket=ST-1"),
+///         // Aurion validates the service ticket
+///         Response::new(StatusCode::FOUND, "")
+///             .with_header(LOCATION, "https://aurion.example/")
+///             .with_header(SET_COOKIE, "JSESSIONID=0123"),
+///         main_page.clone(),
+///         // Language switch
+///         Response::new(StatusCode::OK, "<partial-response />"),
+///         main_page,
+///     ])),
+/// };
+///
+/// let aurion = AurionBuilder::new(
+///     275805,
+///     "submenu_291906",
+///     "1_3",
+///     "submenu_299102",
+///     "https://aurion.example/",
+/// )
+/// .auth_method(AuthMethod::Cas {
+///     cas_url: Url::parse("https://cas.example/cas/").unwrap(),
+/// })
+/// .pin_host(true)
+/// .transport(transport)
+/// .build();
+///
+/// let token = aurion.login("username", "password").await.unwrap();
+/// assert_eq!(token, "JSESSIONID=0123");
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuthMethod {
+    /// The built-in login form of Aurion, posted to its `/login` page.
+    #[default]
+    Form,
+
+    /// A CAS server fronting Aurion, such as the Shibboleth or CAS SSO of
+    /// some schools.
+    ///
+    /// The credentials are posted to the login form of the CAS server, and
+    /// the service ticket it grants is then handed to Aurion, which opens the
+    /// session.
+    Cas {
+        /// The base url of the CAS server, whose login page is `login`.
+        cas_url: Url,
+    },
+}
@@ -0,0 +1,108 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::{debug, trace};
+
+use crate::event::{Event, EventDetails, EventId};
+
+/// An in-memory read-through cache of fetched event details, whose entries
+/// expire after a time to live.
+///
+/// The details of an event are also dropped as soon as the event is seen
+/// modified in a fetched schedule. The cache is disabled when no time to
+/// live is set.
+pub struct DetailsCache {
+    ttl: Option<Duration>,
+    entries: Mutex<HashMap<EventId, (Instant, EventDetails)>>,
+    fingerprints: Mutex<HashMap<EventId, u64>>,
+}
+
+impl DetailsCache {
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            fingerprints: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the cached details of the event, if they have not expired yet.
+    pub fn get(&self, event_id: &EventId) -> Option<EventDetails> {
+        let ttl = self.ttl?;
+
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(event_id) {
+            Some((fetched_at, details)) if fetched_at.elapsed() < ttl => {
+                trace!("Cache hit for event details {}", event_id);
+                Some(details.clone())
+            }
+            Some(_) => {
+                trace!("Cache entry expired for event details {}", event_id);
+                entries.remove(event_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache the details of the event.
+    pub fn insert(&self, details: &EventDetails) {
+        if self.ttl.is_none() {
+            return;
+        }
+
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(details.id.clone(), (Instant::now(), details.clone()));
+    }
+
+    /// Record the state of a fetched event, dropping its cached details if
+    /// the event was modified since it was last seen.
+    pub fn observe(&self, event: &Event) {
+        if self.ttl.is_none() {
+            return;
+        }
+
+        let fingerprint = fingerprint(event);
+        let previous = self
+            .fingerprints
+            .lock()
+            .unwrap()
+            .insert(event.id.clone(), fingerprint);
+        if previous.is_some_and(|previous| previous != fingerprint) {
+            debug!("Event {} was modified, dropping its details", event.id);
+            self.invalidate(&event.id);
+        }
+    }
+
+    /// Remove the cached details of the event.
+    pub fn invalidate(&self, event_id: &EventId) {
+        self.entries.lock().unwrap().remove(event_id);
+    }
+
+    /// Remove every cached event details.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+        self.fingerprints.lock().unwrap().clear();
+    }
+}
+
+/// Hash the fields of an event parsed from Aurion.
+fn fingerprint(event: &Event) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (
+        &event.kind,
+        &event.start,
+        &event.end,
+        &event.rooms,
+        &event.subject,
+        &event.chapter,
+        &event.participants,
+    )
+        .hash(&mut hasher);
+    hasher.finish()
+}
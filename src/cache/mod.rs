@@ -0,0 +1,5 @@
+mod details_cache;
+mod schedule_cache;
+
+pub use details_cache::DetailsCache;
+pub use schedule_cache::ScheduleCache;
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tracing::trace;
+
+use crate::event::Event;
+
+/// The key of a cached schedule: the planning id and the date range.
+type CacheKey = (String, DateTime<Utc>, DateTime<Utc>);
+
+/// An in-memory cache of fetched schedules, whose entries expire after a
+/// time to live.
+///
+/// The cache is disabled when no time to live is set.
+pub struct ScheduleCache {
+    ttl: Option<Duration>,
+    entries: Mutex<HashMap<CacheKey, (Instant, Vec<Event>)>>,
+}
+
+impl ScheduleCache {
+    pub fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the cached schedule of the planning for the given range, if it
+    /// has not expired yet.
+    pub fn get(
+        &self,
+        planning_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Option<Vec<Event>> {
+        let ttl = self.ttl?;
+        let key = (planning_id.to_string(), start, end);
+
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(&key) {
+            Some((fetched_at, events)) if fetched_at.elapsed() < ttl => {
+                trace!("Cache hit for planning {}", planning_id);
+                Some(events.clone())
+            }
+            Some(_) => {
+                trace!("Cache entry expired for planning {}", planning_id);
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache the schedule of the planning for the given range.
+    pub fn insert(
+        &self,
+        planning_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        events: &[Event],
+    ) {
+        if self.ttl.is_none() {
+            return;
+        }
+
+        let key = (planning_id.to_string(), start, end);
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), events.to_vec()));
+    }
+
+    /// Remove every cached schedule.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
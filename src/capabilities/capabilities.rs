@@ -0,0 +1,25 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// The features of Aurion the logged in account can access.
+///
+/// Depending on the persona (student, teacher, staff), Aurion shows different
+/// branches of the sidebar menu.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Whether the account has its own planning.
+    pub own_planning: bool,
+
+    /// Whether the account can see the plannings of the class groups.
+    pub group_plannings: bool,
+
+    /// Whether the account can see the plannings of the teachers.
+    pub teacher_plannings: bool,
+
+    /// Whether the account can see grades.
+    pub grades: bool,
+
+    /// Whether the school enabled the internship section.
+    pub internships: bool,
+}
@@ -0,0 +1,3 @@
+mod capabilities;
+
+pub use capabilities::Capabilities;
@@ -0,0 +1,127 @@
+#![deny(missing_docs)]
+
+use anyhow::{Error, Result};
+use keyring::Entry;
+use secrecy::{ExposeSecret, SecretString};
+use tracing::{debug, error};
+
+use crate::aurion::Aurion;
+use crate::transport::Transport;
+
+impl Aurion {
+    /// Store the password of the given account in the OS keyring, under the
+    /// given service name, to log in later with [`Aurion::login_from_keyring`].
+    ///
+    /// The password replaces the one already stored for the account, if any.
+    ///
+    /// This method requires the `keyring` feature.
+    pub fn store_in_keyring<P: Into<SecretString>>(
+        service: &str,
+        account: &str,
+        password: P,
+    ) -> Result<()> {
+        let password = password.into();
+        match keyring_entry(service, account)?.set_password(password.expose_secret()) {
+            Ok(()) => {
+                debug!("Password of {} stored in the keyring", account);
+                Ok(())
+            }
+            Err(e) => {
+                let message = format!(
+                    "Failed to store the password of {} in the keyring: {}",
+                    account, e
+                );
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        }
+    }
+
+    /// Remove the password of the given account from the OS keyring.
+    ///
+    /// This method requires the `keyring` feature.
+    pub fn remove_from_keyring(service: &str, account: &str) -> Result<()> {
+        match keyring_entry(service, account)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => {
+                let message = format!(
+                    "Failed to remove the password of {} from the keyring: {}",
+                    account, e
+                );
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        }
+    }
+}
+
+impl<T: Transport> Aurion<T> {
+    /// Login to Aurion with the password stored in the OS keyring (Keychain
+    /// on macOS, Credential Manager on Windows, Secret Service on Linux) and
+    /// return the authentication token.
+    ///
+    /// The account is the username, whose password was stored under the
+    /// given service name with [`Aurion::store_in_keyring`], so that scripts
+    /// and config files don't hold plaintext passwords.
+    ///
+    /// On Linux, the password is kept by the Secret Service of the desktop,
+    /// such as GNOME Keyring or KWallet, and persists across reboots. A
+    /// Secret Service must be running when storing and reading it.
+    ///
+    /// This method requires the `keyring` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// # async fn run() -> anyhow::Result<()> {
+    /// // Once, for example from an interactive setup
+    /// Aurion::store_in_keyring("aurion", "username", "password")?;
+    ///
+    /// #     let aurion = Aurion::new(
+    /// #         275805,
+    /// #         "submenu_291906",
+    /// #         "1_3",
+    /// #         "submenu_299102",
+    /// #         "https://web.isen-ouest.fr/webAurion/",
+    /// #     );
+    /// aurion.login_from_keyring("aurion", "username").await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn login_from_keyring(&self, service: &str, account: &str) -> Result<String> {
+        let password = match keyring_entry(service, account)?.get_password() {
+            Ok(password) => SecretString::from(password),
+            Err(keyring::Error::NoEntry) => {
+                let message = format!(
+                    "Failed to login: no password stored in the keyring for {} of {}",
+                    account, service
+                );
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+            Err(e) => {
+                let message = format!(
+                    "Failed to login: failed to read the password of {} from the keyring: {}",
+                    account, e
+                );
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        self.login(account, password).await
+    }
+}
+
+/// Get the keyring entry of the given account of a service.
+fn keyring_entry(service: &str, account: &str) -> Result<Entry> {
+    match Entry::new(service, account) {
+        Ok(entry) => Ok(entry),
+        Err(e) => {
+            let message = format!("Failed to open the keyring entry of {}: {}", account, e);
+            error!("{}", message);
+            Err(Error::msg(message))
+        }
+    }
+}
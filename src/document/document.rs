@@ -0,0 +1,21 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// An administrative document that can be downloaded from the schooling
+/// menu, such as a school certificate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Document {
+    /// The id of the document in the documents table.
+    pub id: String,
+
+    /// The name of the document.
+    pub name: String,
+}
+
+impl Document {
+    /// Create a new document.
+    pub fn new(id: String, name: String) -> Self {
+        Self { id, name }
+    }
+}
@@ -0,0 +1,5 @@
+mod document;
+mod report_card;
+
+pub use document::Document;
+pub use report_card::ReportCard;
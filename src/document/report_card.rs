@@ -0,0 +1,24 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// A grade bulletin published in Aurion, for a semester.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReportCard {
+    /// The id of the report card in the report cards table.
+    pub id: String,
+
+    /// The semester of the report card, as written by Aurion, for example
+    /// "S5" or "2023-2024 Semestre 1".
+    pub semester: String,
+
+    /// The name of the report card.
+    pub name: String,
+}
+
+impl ReportCard {
+    /// Create a new report card.
+    pub fn new(id: String, semester: String, name: String) -> Self {
+        Self { id, semester, name }
+    }
+}
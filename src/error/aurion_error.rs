@@ -0,0 +1,258 @@
+#![deny(missing_docs)]
+
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// The errors of Aurion that callers may want to handle specifically.
+///
+/// These errors are returned wrapped in an [`anyhow::Error`], from which they
+/// can be retrieved with [`anyhow::Error::downcast_ref`].
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::AurionError;
+///
+/// let error = anyhow::Error::new(AurionError::HostMismatch {
+///     expected: "https://web.isen-ouest.fr".to_string(),
+///     found: "https://phishing.example".to_string(),
+/// });
+///
+/// assert!(matches!(
+///     error.downcast_ref::<AurionError>(),
+///     Some(AurionError::HostMismatch { .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AurionError {
+    /// A request or a redirection targeted another host than the pinned
+    /// service host, see
+    /// [`AurionBuilder::pin_host`](crate::AurionBuilder::pin_host).
+    HostMismatch {
+        /// The origin of the service url.
+        expected: String,
+
+        /// The origin of the refused url.
+        found: String,
+    },
+
+    /// The date range of a schedule fetch is invalid, such as an end before
+    /// its start or a range longer than the maximum span.
+    InvalidDateRange {
+        /// The start of the range.
+        start: DateTime<Utc>,
+
+        /// The end of the range.
+        end: DateTime<Utc>,
+
+        /// Why the range is invalid.
+        reason: String,
+    },
+
+    /// A configured menu id was not found in the sidebar, see
+    /// [`Aurion::validate_menu_ids`](crate::Aurion::validate_menu_ids).
+    InvalidMenuId {
+        /// The name of the menu id: `schooling_id`, `user_planning_id` or
+        /// `groups_planning_id`.
+        name: String,
+
+        /// The configured value of the menu id.
+        id: String,
+    },
+
+    /// Aurion accepted the credentials without setting the cookie of the
+    /// session, so that the session can't be authenticated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::transport::{Request, Response, Transport};
+    /// use aurion_rs::{Aurion, AurionError};
+    /// use http::header::LOCATION;
+    /// use http::StatusCode;
+    /// use reqwest::Url;
+    ///
+    /// struct CookielessTransport;
+    ///
+    /// impl Transport for CookielessTransport {
+    ///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+    ///         Ok(Response::new(StatusCode::FOUND, "")
+    ///             .with_header(LOCATION, "https://aurion.example/faces/MainMenuPage.xhtml"))
+    ///     }
+    ///
+    ///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let aurion = Aurion::with_transport(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://aurion.example/",
+    ///     CookielessTransport,
+    /// );
+    /// let error = aurion.login("username", "password").await.unwrap_err();
+    /// assert_eq!(
+    ///     error.downcast_ref::<AurionError>(),
+    ///     Some(&AurionError::MissingSessionCookie)
+    /// );
+    /// # }
+    /// ```
+    MissingSessionCookie,
+
+    /// The account has no groups planning branch in its sidebar, which is
+    /// the case of some first-year accounts, see
+    /// [`Capabilities::group_plannings`](crate::Capabilities::group_plannings).
+    NoGroupPlannings,
+
+    /// Aurion answered with its maintenance page instead of the requested
+    /// page. Callers should back off until the announced end of the
+    /// maintenance, when there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::transport::{Request, Response, Transport};
+    /// use aurion_rs::{Aurion, AurionError};
+    /// use http::StatusCode;
+    /// use reqwest::Url;
+    ///
+    /// struct MaintenanceTransport;
+    ///
+    /// impl Transport for MaintenanceTransport {
+    ///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+    ///         Ok(Response::new(
+    ///             StatusCode::SERVICE_UNAVAILABLE,
+    ///             "<html><head><title>Maintenance</title></head><body>\
+    ///              <h1>Site en maintenance</h1>\
+    ///              <p>Aurion est indisponible jusqu'au 12/03/2024 à 14h00.</p>\
+    ///              </body></html>",
+    ///         ))
+    ///     }
+    ///
+    ///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let aurion = Aurion::with_transport(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://aurion.example/",
+    ///     MaintenanceTransport,
+    /// );
+    /// let error = aurion.login("username", "password").await.unwrap_err();
+    ///
+    /// let until = "2024-03-12T13:00:00Z".parse().unwrap();
+    /// assert_eq!(
+    ///     error.downcast_ref::<AurionError>(),
+    ///     Some(&AurionError::ServerInMaintenance { until: Some(until) })
+    /// );
+    ///
+    /// // Fetching the schedule fails the same way
+    /// let error = aurion.get_user_schedule(None, None).await.unwrap_err();
+    /// assert_eq!(
+    ///     error.downcast_ref::<AurionError>(),
+    ///     Some(&AurionError::ServerInMaintenance { until: Some(until) })
+    /// );
+    /// # }
+    /// ```
+    ServerInMaintenance {
+        /// The announced end of the maintenance, if the page gives one.
+        until: Option<DateTime<Utc>>,
+    },
+
+    /// A request didn't get its response in time, see
+    /// [`Timeouts`](crate::transport::Timeouts).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use aurion_rs::transport::{Request, Response, RetryPolicy, Timeouts, Transport};
+    /// use aurion_rs::{AurionBuilder, AurionError};
+    /// use reqwest::Url;
+    ///
+    /// struct UnresponsiveTransport;
+    ///
+    /// impl Transport for UnresponsiveTransport {
+    ///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+    ///         std::future::pending().await
+    ///     }
+    ///
+    ///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let aurion = AurionBuilder::new(
+    ///     275805,
+    ///     "submenu_291906",
+    ///     "1_3",
+    ///     "submenu_299102",
+    ///     "https://aurion.example/",
+    /// )
+    /// .timeouts(Timeouts::new(Duration::from_millis(10)))
+    /// .retry_policy(RetryPolicy::none())
+    /// .transport(UnresponsiveTransport)
+    /// .build();
+    ///
+    /// let error = aurion.get_user_schedule(None, None).await.unwrap_err();
+    /// assert!(matches!(
+    ///     error.downcast_ref::<AurionError>(),
+    ///     Some(AurionError::Timeout { .. })
+    /// ));
+    /// # }
+    /// ```
+    Timeout {
+        /// The url of the request.
+        url: String,
+
+        /// The timeout of the request.
+        timeout: Duration,
+    },
+}
+
+impl fmt::Display for AurionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AurionError::HostMismatch { expected, found } => write!(
+                f,
+                "Refused to reach {}: the host is pinned to {}",
+                found, expected
+            ),
+            AurionError::InvalidDateRange { start, end, reason } => write!(
+                f,
+                "Invalid date range from {} to {}: {}",
+                start, end, reason
+            ),
+            AurionError::InvalidMenuId { name, id } => {
+                write!(f, "Menu id {} not found in the sidebar for {}", id, name)
+            }
+            AurionError::MissingSessionCookie => {
+                write!(f, "Failed to login: Aurion did not set the session cookie")
+            }
+            AurionError::NoGroupPlannings => {
+                write!(f, "The account has no groups planning branch")
+            }
+            AurionError::ServerInMaintenance { until: Some(until) } => {
+                write!(f, "Aurion is in maintenance until {}", until)
+            }
+            AurionError::ServerInMaintenance { until: None } => {
+                write!(f, "Aurion is in maintenance")
+            }
+            AurionError::Timeout { url, timeout } => {
+                write!(f, "Request to {} timed out after {:?}", url, timeout)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AurionError {}
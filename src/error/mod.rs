@@ -0,0 +1,3 @@
+mod aurion_error;
+
+pub use aurion_error::AurionError;
@@ -0,0 +1,132 @@
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate, NaiveTime};
+use chrono_tz::Tz;
+
+use super::RawEvent;
+
+/// An event whose dates don't match the time range written in its title.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DstAnomaly {
+    /// The id of the event.
+    pub event_id: String,
+
+    /// The offset of the event dates compared to the title time range.
+    pub offset: Duration,
+
+    /// Whether the event dates were corrected to match the title.
+    pub corrected: bool,
+}
+
+/// Detect the events shifted by exactly one hour compared to the time range
+/// of their title, which is the classic symptom of a daylight saving time
+/// mismatch between Aurion and the school timezone.
+///
+/// The title time range is expressed in the school timezone. When every event
+/// of a day is shifted by the same offset, the day is considered affected by
+/// the DST mismatch and, if `correct` is set, the dates of its events are
+/// shifted back to match their titles. Isolated shifted events are only
+/// reported.
+///
+/// Events whose title doesn't start with a time range are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{check_dst, RawEvent};
+///
+/// // 08h00 in Paris is 06:00 UTC after the switch to summer time
+/// let mut events: Vec<RawEvent> = serde_json::from_str(
+///     r#"[{
+///         "id": "42",
+///         "title": "08h00 à 10h00 - B101 - Cours - Mathematics - Vectors - DOE John",
+///         "start": "2023-03-27T07:00:00Z",
+///         "end": "2023-03-27T09:00:00Z",
+///         "allDay": false,
+///         "editable": false,
+///         "className": "CM"
+///     }]"#,
+/// )
+/// .unwrap();
+///
+/// let anomalies = check_dst(&mut events, chrono_tz::Europe::Paris, true);
+/// assert_eq!(anomalies.len(), 1);
+/// assert!(anomalies[0].corrected);
+/// assert_eq!(events[0].start.to_rfc3339(), "2023-03-27T06:00:00+00:00");
+/// ```
+pub fn check_dst(events: &mut [RawEvent], timezone: Tz, correct: bool) -> Vec<DstAnomaly> {
+    // Compute the offset of every event, grouped by day in the school timezone
+    let mut days: HashMap<NaiveDate, Vec<(usize, Option<Duration>)>> = HashMap::new();
+    for (index, event) in events.iter().enumerate() {
+        let (title_start, title_end) = match parse_title_times(&event.title) {
+            Some(times) => times,
+            None => continue,
+        };
+
+        let start = event.start.with_timezone(&timezone);
+        let end = event.end.with_timezone(&timezone);
+        let start_offset = start.time() - title_start;
+        let end_offset = end.time() - title_end;
+
+        let offset = match start_offset == end_offset
+            && start_offset.num_seconds().abs() == Duration::hours(1).num_seconds()
+        {
+            true => Some(start_offset),
+            false => None,
+        };
+
+        days.entry(start.date_naive())
+            .or_default()
+            .push((index, offset));
+    }
+
+    let mut anomalies = Vec::new();
+    let mut days = days.into_iter().collect::<Vec<_>>();
+    days.sort_by_key(|(date, _)| *date);
+
+    for (_, day) in days {
+        // The whole day is affected if every event is shifted the same way
+        let first_offset = day[0].1;
+        let whole_day = first_offset.is_some() && day.iter().all(|(_, o)| *o == first_offset);
+
+        for (index, offset) in day {
+            let offset = match offset {
+                Some(offset) => offset,
+                None => continue,
+            };
+
+            let corrected = correct && whole_day;
+            let event = &mut events[index];
+            if corrected {
+                event.start -= offset;
+                event.end -= offset;
+            }
+
+            anomalies.push(DstAnomaly {
+                event_id: event.id.clone(),
+                offset,
+                corrected,
+            });
+        }
+    }
+
+    anomalies
+}
+
+/// Parse the time range at the start of an event title, of the form
+/// "12h00 à 13h00 - ..." or "12h00 - 13h00 - ...".
+fn parse_title_times(title: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let mut words = title.split_whitespace();
+    let start = words.next()?;
+    let end = words.nth(1)?;
+
+    Some((parse_time(start)?, parse_time(end)?))
+}
+
+/// Parse a time of the form "12h00".
+fn parse_time(time: &str) -> Option<NaiveTime> {
+    let (hours, minutes) = time.split_once('h')?;
+    NaiveTime::from_hms_opt(hours.parse().ok()?, minutes.parse().ok()?, 0)
+}
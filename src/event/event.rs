@@ -2,13 +2,16 @@
 
 use anyhow::{Error, Result};
 use chrono::{DateTime, Utc};
-use log::error;
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
 
-use super::RawEvent;
+use super::{EventId, KindMapping, Participant, ParticipantRole, RawEvent, Room};
+use crate::schedule::GroupId;
 
 /// The kind of an event.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EventKind {
     /// A course.
     Course,
@@ -43,7 +46,7 @@ pub enum EventKind {
 pub struct Event {
     /// The id of the event.
     /// This id is unique for each event.
-    pub id: u32,
+    pub id: EventId,
 
     /// The kind of the event.
     /// This is the type of the event, for example "Course" or "Exam".
@@ -55,8 +58,20 @@ pub struct Event {
     /// The end date and time of the event.
     pub end: DateTime<Utc>,
 
+    /// Whether the event lasts whole days, such as holidays or deadlines,
+    /// in which case its hours are meaningless.
+    pub all_day: bool,
+
+    /// Whether Aurion allows the event to be moved in its planning.
+    pub editable: bool,
+
     /// The rooms where the event takes place.
-    pub rooms: Vec<String>,
+    pub rooms: Vec<Room>,
+
+    /// Whether the event takes place remotely, as detected from the
+    /// "distanciel", "visio" or "Teams" mentions of its title, which holds
+    /// the rooms.
+    pub is_remote: bool,
 
     /// The subject of the event.
     /// This is the name of the course, the name of the exam, etc, for example
@@ -70,46 +85,149 @@ pub struct Event {
     /// The participants of the event.
     /// This is the list of the participants, professors or supervisor, for
     /// example "John Doe" or "Jane Doe".
-    pub participants: Vec<String>,
+    pub participants: Vec<Participant>,
+
+    /// The id of the class group whose planning the event was fetched from.
+    /// This is only known for events of a group schedule, or when Aurion
+    /// sends the resource id of the event.
+    pub group_id: Option<GroupId>,
+
+    /// Why the title of the event could not be parsed, in which case the
+    /// kind is [`EventKind::Other`] and the subject is the raw title.
+    pub diagnostic: Option<String>,
+
+    /// The title of the event, as sent by Aurion before being parsed.
+    pub raw_title: String,
+
+    /// The class name of the event, as sent by Aurion before being mapped
+    /// to its kind.
+    pub raw_class_name: String,
 }
 
 impl Event {
     /// Parse a raw event into an event.
+    ///
+    /// An event whose title can't be parsed isn't an error: it is returned
+    /// with the [`EventKind::Other`] kind, the raw title as subject and the
+    /// reason of the failure as [`diagnostic`](Self::diagnostic).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::event::{Event, EventKind, RawEvent};
+    ///
+    /// let raw_event: RawEvent = serde_json::from_str(
+    ///     r#"{
+    ///         "id": "42",
+    ///         "title": "Journée portes ouvertes en visio",
+    ///         "start": "2023-03-06T08:00:00Z",
+    ///         "end": "2023-03-06T17:00:00Z",
+    ///         "allDay": false,
+    ///         "editable": false,
+    ///         "className": "CM"
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let event = Event::from_raw_event(raw_event).unwrap();
+    /// assert_eq!(event.kind, EventKind::Other);
+    /// assert_eq!(event.subject, "Journée portes ouvertes en visio");
+    /// assert!(event.diagnostic.is_some());
+    /// assert!(event.is_remote);
+    /// assert_eq!(event.raw_class_name, "CM");
+    /// assert!(!event.all_day);
+    /// ```
     pub fn from_raw_event(event: RawEvent) -> Result<Event> {
-        parse_event(event)
+        parse_event(event, &KindMapping::default())
     }
-}
 
-fn map_kind<T: Into<String>>(event_type: T) -> EventKind {
-    match event_type.into().to_lowercase().as_str() {
-        "conges" => EventKind::Leave,
-        "cm" => EventKind::Course,
-        "cours" => EventKind::Course,
-        "est-epreuve" => EventKind::Exam,
-        "evaluation" => EventKind::Exam,
-        "ds" => EventKind::Exam,
-        "reunion" => EventKind::Meeting,
-        "td" => EventKind::SupervisedWork,
-        "cours_td" => EventKind::SupervisedWork,
-        "tp" => EventKind::PracticalWork,
-        "projet" => EventKind::Project,
-        _ => EventKind::Other,
+    /// Parse a raw event into an event, mapping its class name to its kind
+    /// with the given mapping.
+    ///
+    /// See [`Event::from_raw_event`].
+    pub fn from_raw_event_with(event: RawEvent, kind_mapping: &KindMapping) -> Result<Event> {
+        parse_event(event, kind_mapping)
+    }
+
+    /// Get the start date and time of the event in the given timezone,
+    /// usually the school timezone in which the title hours are written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::event::{Event, EventKind};
+    /// use chrono::{TimeZone, Timelike, Utc};
+    ///
+    /// let event = Event {
+    ///     id: 42.into(),
+    ///     kind: EventKind::Course,
+    ///     start: Utc.with_ymd_and_hms(2023, 3, 27, 6, 0, 0).unwrap(),
+    ///     end: Utc.with_ymd_and_hms(2023, 3, 27, 8, 0, 0).unwrap(),
+    ///     all_day: false,
+    ///     editable: false,
+    ///     rooms: vec![],
+    ///     is_remote: false,
+    ///     subject: "Mathematics".to_string(),
+    ///     chapter: None,
+    ///     participants: vec![],
+    ///     group_id: None,
+    ///     diagnostic: None,
+    ///     raw_title: String::new(),
+    ///     raw_class_name: String::new(),
+    /// };
+    ///
+    /// // Paris is in summer time, two hours ahead of UTC
+    /// assert_eq!(event.start_in(chrono_tz::Europe::Paris).hour(), 8);
+    /// assert_eq!(event.end_in(chrono_tz::Europe::Paris).hour(), 10);
+    /// ```
+    pub fn start_in(&self, timezone: Tz) -> DateTime<Tz> {
+        self.start.with_timezone(&timezone)
+    }
+
+    /// Get the end date and time of the event in the given timezone.
+    ///
+    /// See [`Event::start_in`].
+    pub fn end_in(&self, timezone: Tz) -> DateTime<Tz> {
+        self.end.with_timezone(&timezone)
     }
 }
 
 /// Parse a raw event into an event.
-fn parse_event(event: RawEvent) -> Result<Event> {
-    let id: u32 = event.id.parse().unwrap();
-    let kind = map_kind(event.className);
-
-    // Parse the raw title into the room, subject, chapter and participants
-    let result = parse_title(event.title);
-    let (rooms, subject, chapter, participants) = match result {
-        Ok((rooms, subject, chapter, participants)) => (rooms, subject, chapter, participants),
+fn parse_event(event: RawEvent, kind_mapping: &KindMapping) -> Result<Event> {
+    let id = EventId::from(event.id);
+    let kind = kind_mapping.kind(&event.className);
+    let raw_class_name = event.className;
+
+    // The resource id is only sent by some Aurion instances
+    let group_id = match event.extra.get("resourceId") {
+        Some(Value::Number(number)) => number.as_u64().map(|id| GroupId(id as u32)),
+        Some(Value::String(string)) => string.parse().ok().map(GroupId),
+        _ => None,
+    };
+
+    // Parse the raw title into the room, subject, chapter and participants,
+    // falling back to the raw title as subject
+    let (kind, rooms, subject, chapter, participants, diagnostic) = match parse_title(&event.title)
+    {
+        Ok((rooms, subject, chapter, participants)) => {
+            let role = ParticipantRole::guess(&kind);
+            let participants = participants
+                .into_iter()
+                .map(|participant| participant.with_role(role))
+                .collect();
+            (kind, rooms, subject, chapter, participants, None)
+        }
         Err(e) => {
-            let message = format!("Failed to parse event title: {}", e);
-            error!("{}", message);
-            return Err(Error::msg(message));
+            warn!("Failed to parse title of event {}: {}", id, e);
+            let title = event.title.trim().to_string();
+            (
+                EventKind::Other,
+                Vec::new(),
+                title,
+                None,
+                Vec::new(),
+                Some(e.to_string()),
+            )
         }
     };
 
@@ -118,68 +236,86 @@ fn parse_event(event: RawEvent) -> Result<Event> {
         kind,
         start: event.start,
         end: event.end,
+        all_day: event.allDay,
+        editable: event.editable,
+        is_remote: is_remote(&event.title),
         rooms,
         subject,
         chapter,
         participants,
+        group_id,
+        diagnostic,
+        raw_title: event.title,
+        raw_class_name,
     })
 }
 
+/// The words marking an event taking place remotely, in its title or rooms.
+const REMOTE_MARKERS: [&str; 5] = ["distanciel", "à distance", "visio", "en ligne", "teams"];
+
+/// Check whether the title of an event marks it as taking place remotely.
+fn is_remote(title: &str) -> bool {
+    let title = title.to_lowercase();
+    REMOTE_MARKERS.iter().any(|marker| title.contains(marker))
+}
+
+/// The rooms, subject, chapter and participants parsed from an event title.
+type ParsedTitle = (Vec<Room>, String, Option<String>, Vec<Participant>);
+
 /// Parse the title of an event into the room, subject, chapter and participants.
 /// The title is of the form "12h00 à 13h00 - ...".
-fn parse_title<T: Into<String>>(
-    title: T,
-) -> Result<(Vec<String>, String, Option<String>, Vec<String>)> {
+fn parse_title<T: Into<String>>(title: T) -> Result<ParsedTitle> {
     let title = title.into();
-    let mut rooms: Vec<String> = Vec::new();
-    let subject: String;
+    let mut rooms: Vec<Room> = Vec::new();
     let mut chapter: Option<String> = None;
-    let mut participants: Vec<String> = Vec::new();
+    let mut participants: Vec<Participant> = Vec::new();
 
     // Check if whether the title is of the form "12h00 à 13h00 - ..." or
     // "12h00 - 13h00 - ...". The first case is used by ISEN Ouest, the second
-    // by ISEN Lille.
-    if title.chars().nth(6) == Some('à') {
-        // The chapter can contain a separator " - ", so we need to be careful when
-        // splitting the title.
-
-        // Clean the title by removing the first 16 characters.
-        // And then split the title by the end (the last " - " separator)
-        let title = title[16..].rsplit_once(" - ").unwrap().0;
-        let title = title.split(" - ").collect::<Vec<&str>>();
-
-        // The first element is the rooms
-        for room in title[0].split(" / ") {
-            let room = room.trim();
-            rooms.push(room.to_string());
-        }
+    // by ISEN Lille, which is not supported yet.
+    if title.chars().nth(6) != Some('à') {
+        let message = "The title is not of the form \"12h00 à 13h00 - ...\".".to_string();
+        return Err(Error::msg(message));
+    }
 
-        // The third element is the subject
-        subject = title[2].to_string();
+    // The chapter can contain a separator " - ", so we need to be careful when
+    // splitting the title.
 
-        // The fourth to n - 2 elements is the chapter
-        let _chapter = title[3..title.len() - 1].join(" - ");
-        let _chapter = _chapter.trim();
-        if !_chapter.is_empty() {
-            chapter = Some(_chapter.to_string());
-        }
+    // Clean the title by removing the first 16 characters.
+    // And then split the title by the end (the last " - " separator)
+    let title = title.chars().skip(16).collect::<String>();
+    let title = match title.rsplit_once(" - ") {
+        Some((title, _)) => title,
+        None => return Err(Error::msg("The title has no separator.")),
+    };
+    let title = title.split(" - ").collect::<Vec<&str>>();
+    if title.len() < 4 {
+        let message = format!("The title has {} parts instead of at least 4.", title.len());
+        return Err(Error::msg(message));
+    }
+
+    // The first element is the rooms
+    for room in title[0].split(" / ") {
+        let room = room.trim();
+        rooms.push(Room::parse(room));
+    }
 
-        // The last element is the participants
-        for participant in title[title.len() - 1].split(" / ") {
-            let participant = participant.trim();
-            if !participant.is_empty() {
-                participants.push(participant.to_string());
-            }
+    // The third element is the subject
+    let subject = title[2].to_string();
+
+    // The fourth to n - 2 elements is the chapter
+    let _chapter = title[3..title.len() - 1].join(" - ");
+    let _chapter = _chapter.trim();
+    if !_chapter.is_empty() {
+        chapter = Some(_chapter.to_string());
+    }
+
+    // The last element is the participants
+    for participant in title[title.len() - 1].split(" / ") {
+        let participant = participant.trim();
+        if !participant.is_empty() {
+            participants.push(Participant::parse(participant));
         }
-    } else if title.chars().nth(6) == Some('-') {
-        // TODO: Implement the second case (ISEN Lille)
-        panic!("Not implemented yet");
-    } else {
-        let message = format!(
-            "The title is not of the form \"12h00 à 13h00 - ...\" or \"12h00 - 13h00 - ...\"."
-        );
-        error!("{}", message);
-        return Err(Error::msg(message));
     }
 
     Ok((rooms, subject, chapter, participants))
@@ -0,0 +1,34 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use super::EventId;
+
+/// The details of an event, shown by Aurion when an event is clicked.
+///
+/// These details complete the fields parsed from the event title with the
+/// ones only available in the detail view.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventDetails {
+    /// The id of the event.
+    pub id: EventId,
+
+    /// The label of the event type, as written by Aurion, for example
+    /// "Cours magistral" or "Travaux pratiques".
+    pub kind_label: Option<String>,
+
+    /// The subject of the event.
+    pub subject: Option<String>,
+
+    /// The full description of the event.
+    pub description: Option<String>,
+
+    /// The staff of the event, professors or supervisors.
+    pub staff: Vec<String>,
+
+    /// The names of the class groups attending the event.
+    pub groups: Vec<String>,
+
+    /// The rooms where the event takes place.
+    pub rooms: Vec<String>,
+}
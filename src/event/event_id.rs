@@ -0,0 +1,53 @@
+#![deny(missing_docs)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The id of an event, as sent by Aurion.
+///
+/// Most Aurion instances send numeric ids, but some send alphanumeric ones
+/// or numbers too large for an integer, so the id is kept as written.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::EventId;
+///
+/// assert_eq!(EventId::from(42), EventId::from("42"));
+/// assert_eq!(EventId::from("ev-42a").to_string(), "ev-42a");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EventId(pub String);
+
+impl EventId {
+    /// Get the id as written by Aurion.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u32> for EventId {
+    fn from(id: u32) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<&str> for EventId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<String> for EventId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
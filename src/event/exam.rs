@@ -0,0 +1,87 @@
+#![deny(missing_docs)]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Event, EventId, EventKind};
+
+/// An exam listed in the exams page, with its assessment metadata.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Exam {
+    /// The id of the exam in the exams table.
+    pub id: String,
+
+    /// The id of the schedule event of the exam, when Aurion links it.
+    pub event_id: Option<EventId>,
+
+    /// The subject of the exam.
+    pub subject: String,
+
+    /// The start of the exam.
+    pub start: Option<DateTime<Utc>>,
+
+    /// The duration of the exam, in minutes.
+    pub duration: Option<u32>,
+
+    /// The coefficient of the exam.
+    pub coefficient: Option<f32>,
+
+    /// The materials authorized during the exam, as written by Aurion.
+    pub authorized_materials: Option<String>,
+
+    /// The rooms where the exam takes place.
+    pub rooms: Vec<String>,
+}
+
+impl Exam {
+    /// Find the schedule event of the exam among the given events.
+    ///
+    /// The event is looked up by its id when Aurion links it, or else as the
+    /// exam event starting at the same time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::event::{Event, EventKind, Exam};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap();
+    /// let event = Event {
+    ///     id: 42.into(),
+    ///     kind: EventKind::Exam,
+    ///     start,
+    ///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+    ///     all_day: false,
+    ///     editable: false,
+    ///     rooms: vec!["B101".into()],
+    ///     is_remote: false,
+    ///     subject: "Mathematics".to_string(),
+    ///     chapter: None,
+    ///     participants: vec![],
+    ///     group_id: None,
+    ///     diagnostic: None,
+    ///     raw_title: String::new(),
+    ///     raw_class_name: String::new(),
+    /// };
+    ///
+    /// let exam = Exam {
+    ///     subject: "Mathematics".to_string(),
+    ///     start: Some(start),
+    ///     ..Exam::default()
+    /// };
+    ///
+    /// let events = [event];
+    /// assert_eq!(
+    ///     exam.find_event(&events).map(|event| event.id.as_str()),
+    ///     Some("42")
+    /// );
+    /// ```
+    pub fn find_event<'a>(&self, events: &'a [Event]) -> Option<&'a Event> {
+        match &self.event_id {
+            Some(event_id) => events.iter().find(|event| &event.id == event_id),
+            None => events
+                .iter()
+                .find(|event| event.kind == EventKind::Exam && Some(event.start) == self.start),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::EventKind;
+
+/// A map from the class names of the Aurion events to their kind.
+///
+/// The default mapping knows the class names used by ISEN, such as "CM",
+/// "TD" or "DS". Other schools can extend it with their own class names, or
+/// start from an empty mapping. Class names are matched ignoring case, and
+/// unknown ones are mapped to [`EventKind::Other`]. The mapping can be
+/// deserialized from a JSON object, in which case it only holds the given
+/// class names.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{EventKind, KindMapping};
+///
+/// let mapping = KindMapping::new()
+///     .with("amphi", EventKind::Course)
+///     .with("td", EventKind::Other);
+///
+/// assert_eq!(mapping.kind("CM"), EventKind::Course);
+/// assert_eq!(mapping.kind("Amphi"), EventKind::Course);
+/// assert_eq!(mapping.kind("TD"), EventKind::Other);
+/// assert_eq!(mapping.kind("soutenance"), EventKind::Other);
+///
+/// let mapping: KindMapping = serde_json::from_str(r#"{"exam": "Exam"}"#).unwrap();
+/// assert_eq!(mapping.kind("exam"), EventKind::Exam);
+/// assert_eq!(mapping.kind("cm"), EventKind::Other);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KindMapping {
+    kinds: HashMap<String, EventKind>,
+}
+
+impl KindMapping {
+    /// Create the default mapping, with the class names used by ISEN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a mapping without any class name.
+    pub fn empty() -> Self {
+        Self {
+            kinds: HashMap::new(),
+        }
+    }
+
+    /// Map a class name to the given kind, replacing its previous kind.
+    pub fn with<C: AsRef<str>>(mut self, class_name: C, kind: EventKind) -> Self {
+        self.insert(class_name, kind);
+        self
+    }
+
+    /// Map a class name to the given kind, replacing its previous kind.
+    pub fn insert<C: AsRef<str>>(&mut self, class_name: C, kind: EventKind) {
+        self.kinds
+            .insert(class_name.as_ref().trim().to_lowercase(), kind);
+    }
+
+    /// Get the kind of the given class name, or [`EventKind::Other`] if it
+    /// is unknown.
+    pub fn kind<C: AsRef<str>>(&self, class_name: C) -> EventKind {
+        self.kinds
+            .get(&class_name.as_ref().trim().to_lowercase())
+            .cloned()
+            .unwrap_or(EventKind::Other)
+    }
+}
+
+impl Default for KindMapping {
+    fn default() -> Self {
+        Self::empty()
+            .with("conges", EventKind::Leave)
+            .with("cm", EventKind::Course)
+            .with("cours", EventKind::Course)
+            .with("est-epreuve", EventKind::Exam)
+            .with("evaluation", EventKind::Exam)
+            .with("ds", EventKind::Exam)
+            .with("reunion", EventKind::Meeting)
+            .with("td", EventKind::SupervisedWork)
+            .with("cours_td", EventKind::SupervisedWork)
+            .with("tp", EventKind::PracticalWork)
+            .with("projet", EventKind::Project)
+    }
+}
@@ -1,6 +1,26 @@
+mod dst;
 mod event;
+mod event_details;
+mod event_id;
+mod exam;
+mod kind_mapping;
+mod participant;
 mod raw_event;
+mod room;
+mod snapshot;
 
+pub use dst::check_dst;
+pub use dst::DstAnomaly;
 pub use event::Event;
 pub use event::EventKind;
+pub use event_details::EventDetails;
+pub use event_id::EventId;
+pub use exam::Exam;
+pub use kind_mapping::KindMapping;
+pub use participant::Participant;
+pub use participant::ParticipantRole;
 pub use raw_event::RawEvent;
+pub use room::Room;
+pub use snapshot::migrate;
+pub use snapshot::Snapshot;
+pub use snapshot::EVENT_SCHEMA_VERSION;
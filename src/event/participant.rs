@@ -0,0 +1,150 @@
+#![deny(missing_docs)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::EventKind;
+
+/// The role of a participant of an event, guessed from the kind of the
+/// event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ParticipantRole {
+    /// A professor teaching a course, a practical work, etc.
+    Teacher,
+
+    /// A supervisor of an exam.
+    Supervisor,
+
+    /// A participant whose role could not be guessed.
+    #[default]
+    Unknown,
+}
+
+impl ParticipantRole {
+    /// Guess the role of the participants of an event of the given kind.
+    pub fn guess(kind: &EventKind) -> Self {
+        match kind {
+            EventKind::Course
+            | EventKind::PracticalWork
+            | EventKind::SupervisedWork
+            | EventKind::Project => Self::Teacher,
+            EventKind::Exam => Self::Supervisor,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// A participant of an event, parsed from its name as written by Aurion, of
+/// the form "DOE John".
+///
+/// The last name is made of the leading uppercase words of the name and the
+/// first name of the other ones. Both are unset when the name doesn't match
+/// this form, and the raw name is always kept.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::Participant;
+///
+/// let participant = Participant::parse("LE GOFF Jean-Pierre");
+/// assert_eq!(participant.last_name.as_deref(), Some("LE GOFF"));
+/// assert_eq!(participant.first_name.as_deref(), Some("Jean-Pierre"));
+/// assert_eq!(participant, "LE GOFF Jean-Pierre");
+///
+/// let participant = Participant::parse("Jury de soutenance");
+/// assert_eq!(participant.last_name, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Participant {
+    /// The name of the participant, as written by Aurion.
+    pub raw: String,
+
+    /// The last name of the participant, for example "DOE".
+    pub last_name: Option<String>,
+
+    /// The first name of the participant, for example "John".
+    pub first_name: Option<String>,
+
+    /// The guessed role of the participant.
+    pub role: ParticipantRole,
+}
+
+impl Participant {
+    /// Parse a participant from its name, with an unknown role.
+    pub fn parse<T: Into<String>>(raw: T) -> Self {
+        let raw = raw.into().trim().to_string();
+
+        let words = raw.split_whitespace().collect::<Vec<&str>>();
+        let split = words
+            .iter()
+            .position(|word| !is_uppercase(word))
+            .unwrap_or(words.len());
+        let (last_name, first_name) = match split {
+            0 => (None, None),
+            _ if split == words.len() => (Some(words.join(" ")), None),
+            _ => (
+                Some(words[..split].join(" ")),
+                Some(words[split..].join(" ")),
+            ),
+        };
+
+        Self {
+            raw,
+            last_name,
+            first_name,
+            role: ParticipantRole::Unknown,
+        }
+    }
+
+    /// Set the role of the participant.
+    pub fn with_role(mut self, role: ParticipantRole) -> Self {
+        self.role = role;
+        self
+    }
+}
+
+/// Check whether a word has letters, all uppercase.
+fn is_uppercase(word: &str) -> bool {
+    word.chars().any(char::is_alphabetic)
+        && word
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .all(char::is_uppercase)
+}
+
+impl fmt::Display for Participant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl From<String> for Participant {
+    fn from(raw: String) -> Self {
+        Self::parse(raw)
+    }
+}
+
+impl From<&str> for Participant {
+    fn from(raw: &str) -> Self {
+        Self::parse(raw)
+    }
+}
+
+impl AsRef<str> for Participant {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl PartialEq<str> for Participant {
+    fn eq(&self, other: &str) -> bool {
+        self.raw == other
+    }
+}
+
+impl PartialEq<&str> for Participant {
+    fn eq(&self, other: &&str) -> bool {
+        self.raw == *other
+    }
+}
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// RawEvent is the raw event data that is sent to the client.
 /// It is used to create the Event struct.
@@ -22,12 +23,19 @@ pub struct RawEvent {
     /// The end date and time of the event.
     pub end: DateTime<Utc>,
 
-    // The following boolean fields are not used by the client.
-    allDay: bool,
-    editable: bool,
+    /// Whether the event lasts whole days.
+    pub allDay: bool,
+
+    /// Whether the event can be moved in the planning.
+    pub editable: bool,
 
     /// The class name of the event.
     /// The class name is used to determine the kind of the event.
     /// The class name is parsed into the kind of the event.
     pub className: String,
+
+    /// The fields sent by Aurion that are not mapped above, for example
+    /// color hints or resource ids, kept as is so that nothing is lost.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
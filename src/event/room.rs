@@ -0,0 +1,142 @@
+#![deny(missing_docs)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A room where an event takes place, parsed from its name as written by
+/// Aurion, such as "NANTES L012" or "B2-103".
+///
+/// The campus, building and room number are parsed on a best-effort basis:
+/// they are all unset when the name doesn't match one of the known forms,
+/// and the raw name is always kept. Rooms are serialized as their raw name.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::Room;
+///
+/// let room = Room::parse("NANTES L012");
+/// assert_eq!(room.campus.as_deref(), Some("NANTES"));
+/// assert_eq!(room.building.as_deref(), Some("L"));
+/// assert_eq!(room.number.as_deref(), Some("012"));
+///
+/// let room = Room::parse("B2-103");
+/// assert_eq!(room.campus, None);
+/// assert_eq!(room.building.as_deref(), Some("B2"));
+/// assert_eq!(room.number.as_deref(), Some("103"));
+///
+/// let room = Room::parse("Amphi Lumière");
+/// assert_eq!(room.building, None);
+/// assert_eq!(room, "Amphi Lumière");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct Room {
+    /// The name of the room, as written by Aurion.
+    pub raw: String,
+
+    /// The campus of the room, for example "NANTES".
+    pub campus: Option<String>,
+
+    /// The building of the room, for example "B2".
+    pub building: Option<String>,
+
+    /// The number of the room in its building, for example "103".
+    pub number: Option<String>,
+}
+
+impl Room {
+    /// Parse a room from its name.
+    pub fn parse<T: Into<String>>(raw: T) -> Self {
+        let raw = raw.into().trim().to_string();
+
+        // The campus is an uppercase word before the room code
+        let (campus, code) = match raw.split_once(' ') {
+            Some((campus, code))
+                if campus.len() >= 3
+                    && campus.chars().all(|c| c.is_uppercase())
+                    && parse_code(code.trim()).is_some() =>
+            {
+                (Some(campus.to_string()), code.trim())
+            }
+            _ => (None, raw.as_str()),
+        };
+
+        let (building, number) = match parse_code(code) {
+            Some((building, number)) => (Some(building), Some(number)),
+            None => (None, None),
+        };
+
+        Self {
+            campus,
+            building,
+            number,
+            raw,
+        }
+    }
+}
+
+/// Parse a room code into its building and number, either of the form
+/// "B2-103" or "L012".
+fn parse_code(code: &str) -> Option<(String, String)> {
+    if let Some((building, number)) = code.split_once('-') {
+        let is_code = |part: &str| !part.is_empty() && part.chars().all(char::is_alphanumeric);
+        if !is_code(building) || !is_code(number) || !number.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        return Some((building.to_string(), number.to_string()));
+    }
+
+    let split = code.find(|c: char| !c.is_alphabetic())?;
+    let (building, number) = code.split_at(split);
+    if building.is_empty()
+        || building.chars().count() > 3
+        || !number.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((building.to_string(), number.to_string()))
+}
+
+impl fmt::Display for Room {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl From<String> for Room {
+    fn from(raw: String) -> Self {
+        Self::parse(raw)
+    }
+}
+
+impl From<&str> for Room {
+    fn from(raw: &str) -> Self {
+        Self::parse(raw)
+    }
+}
+
+impl From<Room> for String {
+    fn from(room: Room) -> Self {
+        room.raw
+    }
+}
+
+impl AsRef<str> for Room {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl PartialEq<str> for Room {
+    fn eq(&self, other: &str) -> bool {
+        self.raw == other
+    }
+}
+
+impl PartialEq<&str> for Room {
+    fn eq(&self, other: &&str) -> bool {
+        self.raw == *other
+    }
+}
@@ -0,0 +1,275 @@
+#![deny(missing_docs)]
+
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tracing::{debug, error};
+
+use super::{Event, EventKind, Participant, ParticipantRole};
+
+/// The current version of the serialized event schema.
+///
+/// This version is bumped every time the serialized form of [`Event`] changes,
+/// along with a new migration from the previous version.
+pub const EVENT_SCHEMA_VERSION: u32 = 8;
+
+/// A migration from the version at its index to the next one.
+type Migration = fn(Value) -> Result<Value>;
+
+/// The migrations between the schema versions.
+/// The migration at index `n` upgrades a snapshot from version `n` to `n + 1`.
+const MIGRATIONS: [Migration; EVENT_SCHEMA_VERSION as usize] = [
+    migrate_v0_to_v1,
+    migrate_v1_to_v2,
+    migrate_v2_to_v3,
+    migrate_v3_to_v4,
+    migrate_v4_to_v5,
+    migrate_v5_to_v6,
+    migrate_v6_to_v7,
+    migrate_v7_to_v8,
+];
+
+/// A versioned list of events.
+///
+/// Snapshots embed the version of the event schema they were created with,
+/// so that archives created by older versions of the crate can still be read
+/// after the [`Event`] struct is extended.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{ParticipantRole, Snapshot, EVENT_SCHEMA_VERSION};
+///
+/// // Events serialized before the schema was versioned
+/// let snapshot = Snapshot::from_json("[]").unwrap();
+/// assert_eq!(snapshot.version, EVENT_SCHEMA_VERSION);
+///
+/// let json = snapshot.to_json().unwrap();
+/// let snapshot = Snapshot::from_json(&json).unwrap();
+/// assert!(snapshot.events.is_empty());
+///
+/// // Events serialized before the group id was added
+/// let snapshot = Snapshot::from_json(
+///     r#"{"version": 1, "events": [{
+///         "id": 42, "kind": "Course",
+///         "start": "2023-03-06T08:00:00Z", "end": "2023-03-06T10:00:00Z",
+///         "rooms": ["B101"], "subject": "Mathematics", "chapter": null,
+///         "participants": []
+///     }]}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(snapshot.events[0].group_id, None);
+/// assert_eq!(snapshot.events[0].diagnostic, None);
+/// assert_eq!(snapshot.events[0].id.as_str(), "42");
+/// assert!(!snapshot.events[0].is_remote);
+///
+/// // Events serialized before the participants were parsed
+/// let snapshot = Snapshot::from_json(
+///     r#"{"version": 5, "events": [{
+///         "id": "42", "kind": "Course",
+///         "start": "2023-03-06T08:00:00Z", "end": "2023-03-06T10:00:00Z",
+///         "rooms": ["B101"], "is_remote": false, "subject": "Mathematics",
+///         "chapter": null, "participants": ["DOE John"], "group_id": null,
+///         "diagnostic": null
+///     }]}"#,
+/// )
+/// .unwrap();
+/// let participant = &snapshot.events[0].participants[0];
+/// assert_eq!(participant.last_name.as_deref(), Some("DOE"));
+/// assert_eq!(participant.role, ParticipantRole::Teacher);
+/// assert!(snapshot.events[0].raw_title.is_empty());
+/// assert!(!snapshot.events[0].all_day);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// The version of the event schema.
+    pub version: u32,
+
+    /// The events of the snapshot.
+    pub events: Vec<Event>,
+}
+
+impl Snapshot {
+    /// Create a new snapshot of the given events with the current schema
+    /// version.
+    pub fn new(events: Vec<Event>) -> Self {
+        Self {
+            version: EVENT_SCHEMA_VERSION,
+            events,
+        }
+    }
+
+    /// Serialize the snapshot to JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserialize a snapshot from JSON, migrating it to the current schema
+    /// version if needed.
+    pub fn from_json<T: AsRef<str>>(json: T) -> Result<Self> {
+        let value: Value = serde_json::from_str(json.as_ref())?;
+        let value = migrate(value)?;
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+/// Migrate a serialized snapshot to the current schema version.
+///
+/// A bare JSON array of events is considered to be a snapshot of version 0,
+/// as events were serialized without a version before.
+pub fn migrate(value: Value) -> Result<Value> {
+    let mut version = match &value {
+        Value::Array(_) => 0,
+        Value::Object(object) => match object.get("version").and_then(Value::as_u64) {
+            Some(version) => version as u32,
+            None => {
+                let message = "Failed to migrate snapshot: version not found".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        },
+        _ => {
+            let message = "Failed to migrate snapshot: invalid snapshot".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+    };
+
+    if version > EVENT_SCHEMA_VERSION {
+        let message = format!(
+            "Failed to migrate snapshot: version {} is newer than the supported version {}",
+            version, EVENT_SCHEMA_VERSION
+        );
+        error!("{}", message);
+        return Err(Error::msg(message));
+    }
+
+    let mut value = value;
+    while version < EVENT_SCHEMA_VERSION {
+        debug!("Migrating snapshot from version {}", version);
+        value = MIGRATIONS[version as usize](value)?;
+        version += 1;
+    }
+
+    Ok(value)
+}
+
+/// Wrap the bare list of events into a versioned snapshot.
+fn migrate_v0_to_v1(value: Value) -> Result<Value> {
+    Ok(json!({
+        "version": 1,
+        "events": value,
+    }))
+}
+
+/// Add the unknown group id to the events.
+fn migrate_v1_to_v2(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        event.insert("group_id".to_string(), Value::Null);
+    })?;
+    value["version"] = json!(2);
+    Ok(value)
+}
+
+/// Add the missing parse diagnostic to the events, as they were all parsed
+/// from their title.
+fn migrate_v2_to_v3(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        event.insert("diagnostic".to_string(), Value::Null);
+    })?;
+    value["version"] = json!(3);
+    Ok(value)
+}
+
+/// Convert the numeric event ids to strings, as ids are no longer required
+/// to be numbers.
+fn migrate_v3_to_v4(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        if let Some(Value::Number(id)) = event.get("id") {
+            let id = id.to_string();
+            event.insert("id".to_string(), Value::String(id));
+        }
+    })?;
+    value["version"] = json!(4);
+    Ok(value)
+}
+
+/// Mark the events as taking place on campus, as remote events were not
+/// detected.
+fn migrate_v4_to_v5(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        event.insert("is_remote".to_string(), Value::Bool(false));
+    })?;
+    value["version"] = json!(5);
+    Ok(value)
+}
+
+/// Parse the participant names into structured participants, guessing
+/// their role from the kind of their event.
+fn migrate_v5_to_v6(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        let role = event
+            .get("kind")
+            .cloned()
+            .and_then(|kind| serde_json::from_value::<EventKind>(kind).ok())
+            .map(|kind| ParticipantRole::guess(&kind))
+            .unwrap_or_default();
+        if let Some(Value::Array(participants)) = event.get_mut("participants") {
+            for participant in participants.iter_mut() {
+                if let Value::String(name) = participant {
+                    let parsed = Participant::parse(name.as_str()).with_role(role);
+                    *participant = json!(parsed);
+                }
+            }
+        }
+    })?;
+    value["version"] = json!(6);
+    Ok(value)
+}
+
+/// Add the raw title and class name to the events, left empty as they were
+/// not kept.
+fn migrate_v6_to_v7(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        event.insert("raw_title".to_string(), json!(""));
+        event.insert("raw_class_name".to_string(), json!(""));
+    })?;
+    value["version"] = json!(7);
+    Ok(value)
+}
+
+/// Add the all-day and editable flags to the events, unset as they were
+/// not kept.
+fn migrate_v7_to_v8(mut value: Value) -> Result<Value> {
+    for_each_event(&mut value, |event| {
+        event.insert("all_day".to_string(), Value::Bool(false));
+        event.insert("editable".to_string(), Value::Bool(false));
+    })?;
+    value["version"] = json!(8);
+    Ok(value)
+}
+
+/// Apply the given function to every serialized event of a snapshot.
+fn for_each_event<F: FnMut(&mut Map<String, Value>)>(value: &mut Value, mut f: F) -> Result<()> {
+    let events = match value.get_mut("events").and_then(Value::as_array_mut) {
+        Some(events) => events,
+        None => {
+            let message = "Failed to migrate snapshot: events not found".to_string();
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+    };
+
+    for event in events {
+        match event.as_object_mut() {
+            Some(event) => f(event),
+            None => {
+                let message = "Failed to migrate snapshot: invalid event".to_string();
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+#![deny(missing_docs)]
+
+//! Format events as Discord rich embeds, as sent by bots in the `embeds`
+//! array of a message.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aurion_rs::event::{Event, EventKind};
+//! use aurion_rs::export::discord;
+//! use chrono::{NaiveDate, TimeZone, Utc};
+//!
+//! let event = Event {
+//!     id: 42.into(),
+//!     kind: EventKind::Exam,
+//!     start: Utc.with_ymd_and_hms(2024, 2, 12, 7, 0, 0).unwrap(),
+//!     end: Utc.with_ymd_and_hms(2024, 2, 12, 9, 0, 0).unwrap(),
+//!     all_day: false,
+//!     editable: false,
+//!     rooms: vec!["B101".into()],
+//!     is_remote: false,
+//!     subject: "Mathematics".to_string(),
+//!     chapter: None,
+//!     participants: vec!["DOE John".into()],
+//!     group_id: None,
+//!     diagnostic: None,
+//!     raw_title: String::new(),
+//!     raw_class_name: String::new(),
+//! };
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 2, 12).unwrap();
+//! let embed = discord::embed_for_day(&[event], date);
+//! assert_eq!(embed["title"], "lundi 12 février");
+//! assert_eq!(embed["color"], discord::kind_color(&EventKind::Exam));
+//! assert_eq!(embed["fields"][0]["name"], "🟥 08:00 - 10:00 · Mathematics");
+//! assert_eq!(embed["fields"][0]["value"], "B101 · DOE John");
+//! ```
+
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+use serde_json::{json, Value};
+
+use crate::event::{Event, EventKind};
+use crate::format::{format_day, Locale, Translations};
+
+/// The maximum number of fields of an embed.
+const MAX_FIELDS: usize = 25;
+
+/// The maximum length of a field name, in characters.
+const MAX_NAME_LENGTH: usize = 256;
+
+/// The maximum length of a field value, in characters.
+const MAX_VALUE_LENGTH: usize = 1024;
+
+/// The color of an embed without events.
+const DEFAULT_COLOR: u32 = 0x99aab5;
+
+/// Build the embed of the events of a day, in the `Europe/Paris` timezone
+/// and in French.
+///
+/// See [`embed_for_day_in`].
+pub fn embed_for_day(events: &[Event], date: NaiveDate) -> Value {
+    embed_for_day_in(events, date, chrono_tz::Europe::Paris, Locale::French)
+}
+
+/// Build the embed of the events of a day in the given timezone, with one
+/// field per event sorted by start date.
+///
+/// The field names are prefixed by a colored square matching the kind of
+/// the event, and the embed takes the color of the first exam of the day, or
+/// else of its first event. Events beyond the 25 fields allowed by Discord
+/// are left out.
+pub fn embed_for_day_in(events: &[Event], date: NaiveDate, timezone: Tz, locale: Locale) -> Value {
+    embed_for_day_translated(events, date, timezone, locale, &Translations::new())
+}
+
+/// Build the embed of the events of a day in the given timezone, with their
+/// subjects translated with the given translations.
+///
+/// See [`embed_for_day_in`].
+pub fn embed_for_day_translated(
+    events: &[Event],
+    date: NaiveDate,
+    timezone: Tz,
+    locale: Locale,
+    translations: &Translations,
+) -> Value {
+    let mut events = events
+        .iter()
+        .filter(|event| event.start.with_timezone(&timezone).date_naive() == date)
+        .collect::<Vec<&Event>>();
+    events.sort_by_key(|event| event.start);
+
+    let color = events
+        .iter()
+        .find(|event| event.kind == EventKind::Exam)
+        .or(events.first())
+        .map_or(DEFAULT_COLOR, |event| kind_color(&event.kind));
+
+    let fields = events
+        .iter()
+        .take(MAX_FIELDS)
+        .map(|event| {
+            let name = format!(
+                "{} {} - {} · {}",
+                kind_square(&event.kind),
+                event.start.with_timezone(&timezone).format("%H:%M"),
+                event.end.with_timezone(&timezone).format("%H:%M"),
+                translations.translate(&event.subject)
+            );
+
+            let mut details = Vec::new();
+            if !event.rooms.is_empty() {
+                details.push(
+                    event
+                        .rooms
+                        .iter()
+                        .map(|room| room.raw.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", "),
+                );
+            }
+            if !event.participants.is_empty() {
+                details.push(
+                    event
+                        .participants
+                        .iter()
+                        .map(|participant| participant.raw.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", "),
+                );
+            }
+            let value = match details.is_empty() {
+                true => "-".to_string(),
+                false => details.join(" · "),
+            };
+
+            json!({
+                "name": truncate(&name, MAX_NAME_LENGTH),
+                "value": truncate(&value, MAX_VALUE_LENGTH),
+                "inline": false,
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    let description = match (fields.is_empty(), locale) {
+        (true, Locale::French) => Some("Aucun évènement"),
+        (true, Locale::English) => Some("No events"),
+        (false, _) => None,
+    };
+
+    let mut embed = json!({
+        "title": format_day(date, locale),
+        "color": color,
+        "fields": fields,
+    });
+    if let Some(description) = description {
+        embed["description"] = json!(description);
+    }
+    embed
+}
+
+/// Get the color of an event kind, as an RGB integer.
+pub fn kind_color(kind: &EventKind) -> u32 {
+    match kind {
+        EventKind::Course => 0x3498db,
+        EventKind::Exam => 0xe74c3c,
+        EventKind::Leave => 0x95a5a6,
+        EventKind::Meeting => 0x9b59b6,
+        EventKind::PracticalWork => 0x2ecc71,
+        EventKind::SupervisedWork => 0xf1c40f,
+        EventKind::Project => 0xe67e22,
+        EventKind::Other => DEFAULT_COLOR,
+    }
+}
+
+/// Get the colored square emoji of an event kind.
+fn kind_square(kind: &EventKind) -> &'static str {
+    match kind {
+        EventKind::Course => "🟦",
+        EventKind::Exam => "🟥",
+        EventKind::Leave => "⬜",
+        EventKind::Meeting => "🟪",
+        EventKind::PracticalWork => "🟩",
+        EventKind::SupervisedWork => "🟨",
+        EventKind::Project => "🟧",
+        EventKind::Other => "⬛",
+    }
+}
+
+/// Truncate a text to the given number of characters, ending it with an
+/// ellipsis if it was truncated.
+fn truncate(text: &str, length: usize) -> String {
+    match text.chars().count() > length {
+        true => {
+            let mut text = text.chars().take(length - 1).collect::<String>();
+            text.push('…');
+            text
+        }
+        false => text.to_string(),
+    }
+}
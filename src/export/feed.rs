@@ -0,0 +1,164 @@
+#![deny(missing_docs)]
+
+//! Export the upcoming events as a syndication feed, for feed readers and
+//! integrations that can't consume iCalendar data.
+//!
+//! The id of every entry is its [`event_uid`], like the UID of the event in
+//! the iCalendar exports, and its update date is the start of the event, so
+//! that feed readers don't see the entries change on every generation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aurion_rs::event::{Event, EventKind};
+//! use aurion_rs::export::feed;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let event = Event {
+//!     id: 42.into(),
+//!     kind: EventKind::Course,
+//!     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+//!     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+//!     all_day: false,
+//!     editable: false,
+//!     rooms: vec!["B101".into()],
+//!     is_remote: false,
+//!     subject: "Maths & Physics".to_string(),
+//!     chapter: None,
+//!     participants: vec![],
+//!     group_id: None,
+//!     diagnostic: None,
+//!     raw_title: String::new(),
+//!     raw_class_name: String::new(),
+//! };
+//!
+//! let now = Utc.with_ymd_and_hms(2023, 3, 5, 12, 0, 0).unwrap();
+//! let atom = feed::atom_at(&[event.clone()], "user:1_3", now);
+//! assert!(atom.contains("<title>Maths &amp; Physics</title>"));
+//! assert!(atom.contains("<id>42-5ea3a0232928551d@aurion_rs</id>"));
+//!
+//! // The entries stay the same from one generation to the next
+//! let entry = "<updated>2023-03-06T08:00:00+00:00</updated>";
+//! assert!(atom.contains(entry));
+//! let later = Utc.with_ymd_and_hms(2023, 3, 5, 18, 0, 0).unwrap();
+//! assert!(feed::atom_at(&[event.clone()], "user:1_3", later).contains(entry));
+//!
+//! // Events beyond the next 7 days are left out
+//! let earlier = Utc.with_ymd_and_hms(2023, 2, 20, 12, 0, 0).unwrap();
+//! assert!(!feed::atom_at(&[event], "user:1_3", earlier).contains("<entry>"));
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::event::Event;
+use crate::format::Translations;
+use crate::uid::event_uid;
+
+/// The number of days of upcoming events included in the feeds.
+const FEED_DAYS: i64 = 7;
+
+/// Generate an Atom feed of the events of the next 7 days.
+///
+/// The `scope` identifies where the events come from, see [`event_uid`].
+pub fn atom<S: AsRef<str>>(events: &[Event], scope: S) -> String {
+    atom_at(events, scope, Utc::now())
+}
+
+/// Generate an Atom feed of the events of the 7 days following `now`.
+///
+/// The events are sorted by start date, and events that already started are
+/// left out.
+pub fn atom_at<S: AsRef<str>>(events: &[Event], scope: S, now: DateTime<Utc>) -> String {
+    atom_translated(events, scope, now, &Translations::new())
+}
+
+/// Generate an Atom feed of the events of the 7 days following `now`, with
+/// their subjects translated with the given translations.
+///
+/// See [`atom_at`].
+pub fn atom_translated<S: AsRef<str>>(
+    events: &[Event],
+    scope: S,
+    now: DateTime<Utc>,
+    translations: &Translations,
+) -> String {
+    let end = now + Duration::days(FEED_DAYS);
+    let mut events = events
+        .iter()
+        .filter(|event| event.start >= now && event.start < end)
+        .collect::<Vec<&Event>>();
+    events.sort_by_key(|event| event.start);
+
+    let mut text = String::new();
+    text.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    text.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    text.push_str("  <id>urn:aurion_rs:upcoming</id>\n");
+    text.push_str("  <title>Upcoming events</title>\n");
+    text.push_str(&format!("  <updated>{}</updated>\n", now.to_rfc3339()));
+    text.push_str("  <author><name>aurion_rs</name></author>\n");
+
+    for event in events {
+        text.push_str("  <entry>\n");
+        text.push_str(&format!(
+            "    <id>{}</id>\n",
+            escape(&event_uid(event, scope.as_ref()))
+        ));
+        text.push_str(&format!(
+            "    <title>{}</title>\n",
+            escape(translations.translate(&event.subject))
+        ));
+        text.push_str(&format!(
+            "    <updated>{}</updated>\n",
+            event.start.to_rfc3339()
+        ));
+        text.push_str(&format!(
+            "    <summary>{}</summary>\n",
+            escape(&summary(event))
+        ));
+        text.push_str("  </entry>\n");
+    }
+
+    text.push_str("</feed>\n");
+    text
+}
+
+/// Summarize the date, the rooms and the participants of an event.
+fn summary(event: &Event) -> String {
+    let mut parts = vec![format!(
+        "{} - {} UTC",
+        event.start.format("%Y-%m-%d %H:%M"),
+        event.end.format("%H:%M")
+    )];
+    if let Some(chapter) = &event.chapter {
+        parts.push(chapter.clone());
+    }
+    if !event.rooms.is_empty() {
+        parts.push(
+            event
+                .rooms
+                .iter()
+                .map(|room| room.raw.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+        );
+    }
+    if !event.participants.is_empty() {
+        parts.push(
+            event
+                .participants
+                .iter()
+                .map(|participant| participant.raw.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+        );
+    }
+    parts.join(" | ")
+}
+
+/// Escape a text for XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
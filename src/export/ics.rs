@@ -0,0 +1,280 @@
+#![deny(missing_docs)]
+
+//! Export events as iCalendar (RFC 5545) data.
+//!
+//! The UID of every event is its [`event_uid`], so that calendars importing
+//! the same events again update them instead of creating duplicates.
+//!
+//! All-day events are written with dates instead of date-times, so that
+//! calendars display them as whole days.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aurion_rs::event::{Event, EventKind};
+//! use aurion_rs::export::ics;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let event = Event {
+//!     id: 42.into(),
+//!     kind: EventKind::Course,
+//!     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+//!     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+//!     all_day: false,
+//!     editable: false,
+//!     rooms: vec!["B101".into()],
+//!     is_remote: false,
+//!     subject: "Mathematics".to_string(),
+//!     chapter: Some("Vectors, lines; planes".to_string()),
+//!     participants: vec![],
+//!     group_id: None,
+//!     diagnostic: None,
+//!     raw_title: String::new(),
+//!     raw_class_name: String::new(),
+//! };
+//!
+//! let calendar = ics::to_calendar(&[event], "user:1_3");
+//! assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+//! assert!(calendar.contains("UID:42-5ea3a0232928551d@aurion_rs\r\n"));
+//! assert!(calendar.contains("DTSTART:20230306T080000Z\r\n"));
+//! assert!(calendar.contains("DESCRIPTION:Vectors\\, lines\\; planes\r\n"));
+//! ```
+
+use chrono::{DateTime, Days, Duration, NaiveDate, Utc};
+
+use crate::event::{Event, EventKind};
+use crate::format::Translations;
+use crate::uid::event_uid;
+
+/// The maximum length of a content line, in octets, before it is folded.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// Serialize events to an iCalendar calendar.
+///
+/// The `scope` identifies where the events come from, see [`event_uid`].
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::export::ics;
+/// use chrono::{TimeZone, Utc};
+///
+/// // A holiday, from midnight to midnight in Paris
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Leave,
+///     start: Utc.with_ymd_and_hms(2023, 3, 5, 23, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 23, 0, 0).unwrap(),
+///     all_day: true,
+///     editable: false,
+///     rooms: vec![],
+///     is_remote: false,
+///     subject: "Holiday".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+///
+/// let calendar = ics::to_calendar(&[event], "user:1_3");
+/// assert!(calendar.contains("DTSTART;VALUE=DATE:20230306\r\n"));
+/// assert!(calendar.contains("DTEND;VALUE=DATE:20230307\r\n"));
+/// ```
+pub fn to_calendar<S: AsRef<str>>(events: &[Event], scope: S) -> String {
+    to_calendar_translated(events, scope, &Translations::new())
+}
+
+/// Serialize events to an iCalendar calendar, with their subjects
+/// translated with the given translations.
+///
+/// See [`to_calendar`].
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::export::ics;
+/// use aurion_rs::format::Translations;
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec![],
+///     is_remote: false,
+///     subject: "Mathématiques".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+///
+/// let translations = Translations::new().with("Mathématiques", "Mathematics");
+/// let calendar = ics::to_calendar_translated(&[event], "user:1_3", &translations);
+/// assert!(calendar.contains("SUMMARY:Mathematics\r\n"));
+/// ```
+pub fn to_calendar_translated<S: AsRef<str>>(
+    events: &[Event],
+    scope: S,
+    translations: &Translations,
+) -> String {
+    let mut text = String::new();
+    push_line(&mut text, "BEGIN:VCALENDAR");
+    push_line(&mut text, "VERSION:2.0");
+    push_line(&mut text, "PRODID:-//aurion_rs//aurion_rs//EN");
+    push_line(&mut text, "CALSCALE:GREGORIAN");
+    let now = Utc::now();
+    for event in events {
+        push_event(&mut text, event, scope.as_ref(), translations, now);
+    }
+    push_line(&mut text, "END:VCALENDAR");
+    text
+}
+
+/// Serialize a single event to an iCalendar calendar, as stored by CalDAV
+/// servers.
+pub fn to_event<S: AsRef<str>>(event: &Event, scope: S) -> String {
+    to_calendar(std::slice::from_ref(event), scope)
+}
+
+/// Serialize a single event to an iCalendar calendar, with its subject
+/// translated with the given translations.
+///
+/// See [`to_event`].
+pub fn to_event_translated<S: AsRef<str>>(
+    event: &Event,
+    scope: S,
+    translations: &Translations,
+) -> String {
+    to_calendar_translated(std::slice::from_ref(event), scope, translations)
+}
+
+/// Append the VEVENT component of an event.
+fn push_event(
+    text: &mut String,
+    event: &Event,
+    scope: &str,
+    translations: &Translations,
+    now: DateTime<Utc>,
+) {
+    push_line(text, "BEGIN:VEVENT");
+    push_line(text, &format!("UID:{}", event_uid(event, scope)));
+    push_line(text, &format!("DTSTAMP:{}", format_date_time(now)));
+    if event.all_day {
+        // The end date is exclusive, and must follow the start date
+        let start = all_day_date(event.start);
+        let end = all_day_date(event.end).max(start + Days::new(1));
+        push_line(
+            text,
+            &format!("DTSTART;VALUE=DATE:{}", start.format("%Y%m%d")),
+        );
+        push_line(text, &format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+    } else {
+        push_line(text, &format!("DTSTART:{}", format_date_time(event.start)));
+        push_line(text, &format!("DTEND:{}", format_date_time(event.end)));
+    }
+    push_line(
+        text,
+        &format!("SUMMARY:{}", escape(translations.translate(&event.subject))),
+    );
+    if !event.rooms.is_empty() {
+        push_line(
+            text,
+            &format!(
+                "LOCATION:{}",
+                escape(
+                    &event
+                        .rooms
+                        .iter()
+                        .map(|room| room.raw.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )
+            ),
+        );
+    }
+
+    let mut description = Vec::new();
+    if let Some(chapter) = &event.chapter {
+        description.push(chapter.clone());
+    }
+    if !event.participants.is_empty() {
+        description.push(
+            event
+                .participants
+                .iter()
+                .map(|participant| participant.raw.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+        );
+    }
+    if !description.is_empty() {
+        push_line(
+            text,
+            &format!("DESCRIPTION:{}", escape(&description.join("\n"))),
+        );
+    }
+
+    push_line(text, &format!("CATEGORIES:{}", category(&event.kind)));
+    push_line(text, "END:VEVENT");
+}
+
+/// Get the category of an event kind.
+fn category(kind: &EventKind) -> &'static str {
+    match kind {
+        EventKind::Course => "COURSE",
+        EventKind::Exam => "EXAM",
+        EventKind::Leave => "LEAVE",
+        EventKind::Meeting => "MEETING",
+        EventKind::PracticalWork => "PRACTICAL-WORK",
+        EventKind::SupervisedWork => "SUPERVISED-WORK",
+        EventKind::Project => "PROJECT",
+        EventKind::Other => "OTHER",
+    }
+}
+
+/// Format a date and time in the UTC form "20230306T080000Z".
+fn format_date_time(date_time: DateTime<Utc>) -> String {
+    date_time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Get the date of a bound of an all-day event, which lies at midnight in
+/// the school timezone.
+///
+/// The bound is rounded to the nearest midnight UTC, whose date is the one of
+/// the local midnight for any timezone within 12 hours of UTC.
+fn all_day_date(date_time: DateTime<Utc>) -> NaiveDate {
+    (date_time + Duration::hours(12)).date_naive()
+}
+
+/// Escape a text value.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Append a content line, folded to lines of at most 75 octets, and ended
+/// with CRLF.
+fn push_line(text: &mut String, line: &str) {
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > MAX_LINE_LENGTH {
+            text.push_str("\r\n ");
+            length = 1;
+        }
+        text.push(character);
+        length += character.len_utf8();
+    }
+    text.push_str("\r\n");
+}
@@ -0,0 +1,4 @@
+pub mod discord;
+pub mod feed;
+pub mod ics;
+pub mod ndjson;
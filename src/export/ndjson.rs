@@ -0,0 +1,89 @@
+#![deny(missing_docs)]
+
+//! Export events as newline-delimited JSON (NDJSON), one event per line.
+//!
+//! Each line is the JSON object of an [`Event`] with an additional
+//! `schema_version` field holding [`EVENT_SCHEMA_VERSION`], so that lines
+//! written by different versions of the crate can be told apart.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aurion_rs::event::{Event, EventKind, EVENT_SCHEMA_VERSION};
+//! use aurion_rs::export::ndjson;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let event = Event {
+//!     id: 42.into(),
+//!     kind: EventKind::Course,
+//!     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+//!     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+//!     all_day: false,
+//!     editable: false,
+//!     rooms: vec!["B101".into()],
+//!     is_remote: false,
+//!     subject: "Mathematics".to_string(),
+//!     chapter: None,
+//!     participants: vec![],
+//!     group_id: None,
+//!     diagnostic: None,
+//!     raw_title: String::new(),
+//!     raw_class_name: String::new(),
+//! };
+//!
+//! let text = ndjson::to_string(&[event.clone(), event]).unwrap();
+//! assert_eq!(text.lines().count(), 2);
+//!
+//! let line: serde_json::Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+//! assert_eq!(line["schema_version"], EVENT_SCHEMA_VERSION);
+//! assert_eq!(line["subject"], "Mathematics");
+//! ```
+
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use serde_json::{json, Value};
+
+use crate::event::{Event, EVENT_SCHEMA_VERSION};
+
+/// Serialize an event to a single NDJSON line, without the newline.
+pub fn to_line(event: &Event) -> Result<String> {
+    let mut value = json!({ "schema_version": EVENT_SCHEMA_VERSION });
+    match serde_json::to_value(event)? {
+        Value::Object(fields) => value.as_object_mut().unwrap().extend(fields),
+        _ => return Err(Error::msg("Event is not serialized as an object")),
+    }
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Serialize events to NDJSON, each line ending with a newline.
+pub fn to_string(events: &[Event]) -> Result<String> {
+    let mut text = String::new();
+    for event in events {
+        text.push_str(&to_line(event)?);
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// Write events as NDJSON to the given writer.
+pub fn write<W: Write>(writer: W, events: &[Event]) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    for event in events {
+        writer.write_all(to_line(event)?.as_bytes())?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Append events as NDJSON to the file at the given path, creating it if it
+/// doesn't exist.
+///
+/// This is suited to keep a log of every observed event over time.
+pub fn append<P: AsRef<Path>>(path: P, events: &[Event]) -> Result<()> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    write(file, events)
+}
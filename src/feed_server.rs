@@ -0,0 +1,255 @@
+#![deny(missing_docs)]
+
+//! A tiny HTTP server exposing the user's schedule as an iCalendar feed.
+//!
+//! The server answers `GET /calendar.ics` with the user's schedule around
+//! the current day, refetched from Aurion on a configurable interval, so
+//! that phones and calendar apps can subscribe to it directly.
+//!
+//! This module requires the `feed-server` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use aurion_rs::Aurion;
+//! use std::time::Duration;
+//!
+//! use aurion_rs::feed_server::FeedServer;
+//!
+//! # async fn run(aurion: Aurion) -> anyhow::Result<()> {
+//! FeedServer::new(aurion, "127.0.0.1:8080".parse()?)
+//!     .refresh_interval(Duration::from_secs(30 * 60))
+//!     .range(7, 28)
+//!     .run()
+//!     .await?;
+//! #     Ok(())
+//! # }
+//! ```
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{Days, Utc};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::{timeout, Instant};
+use tracing::{debug, info, warn};
+
+use crate::aurion::Aurion;
+use crate::export::ics;
+use crate::transport::{ReqwestTransport, Transport};
+
+/// The path of the feed.
+const FEED_PATH: &str = "/calendar.ics";
+
+/// The maximum size of a request head, in bytes.
+const MAX_REQUEST_SIZE: usize = 8192;
+
+/// The maximum time to wait for a client to send its request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A server exposing the user's schedule as an iCalendar feed.
+///
+/// The Aurion session must be logged in before the server is run. Requests
+/// are answered one at a time, which is enough for a personal feed.
+pub struct FeedServer<T: Transport = ReqwestTransport> {
+    aurion: Aurion<T>,
+    address: SocketAddr,
+    refresh_interval: Duration,
+    past_days: u64,
+    future_days: u64,
+}
+
+impl<T: Transport> FeedServer<T> {
+    /// Create a new server listening on the given address.
+    ///
+    /// By default the schedule is refreshed every hour, from 7 days before
+    /// to 28 days after the current day.
+    pub fn new(aurion: Aurion<T>, address: SocketAddr) -> Self {
+        Self {
+            aurion,
+            address,
+            refresh_interval: Duration::from_secs(60 * 60),
+            past_days: 7,
+            future_days: 28,
+        }
+    }
+
+    /// Set the interval between two refreshes of the schedule.
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Set the number of days before and after the current day included in
+    /// the feed.
+    pub fn range(mut self, past_days: u64, future_days: u64) -> Self {
+        self.past_days = past_days;
+        self.future_days = future_days;
+        self
+    }
+
+    /// Run the server until an error occurs while listening.
+    ///
+    /// Failures to refresh the schedule are only logged, and the last
+    /// fetched schedule keeps being served.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aurion_rs::Aurion;
+    /// use aurion_rs::feed_server::FeedServer;
+    ///
+    /// # async fn run(aurion: Aurion) -> anyhow::Result<()> {
+    /// aurion.login("username", "password").await?;
+    /// FeedServer::new(aurion, "127.0.0.1:8080".parse()?).run().await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn run(mut self) -> Result<()> {
+        let listener = TcpListener::bind(self.address).await?;
+        info!("Serving the feed on http://{}{}", self.address, FEED_PATH);
+
+        let mut calendar = None;
+        let mut next_refresh = Instant::now();
+        loop {
+            if Instant::now() >= next_refresh {
+                match self.refresh().await {
+                    Ok(text) => calendar = Some(text),
+                    Err(e) => warn!("Failed to refresh the feed: {}", e),
+                }
+                next_refresh = Instant::now() + self.refresh_interval;
+            }
+
+            // Wait for a client until the next refresh is due
+            let wait = next_refresh.saturating_duration_since(Instant::now());
+            let stream = match timeout(wait, listener.accept()).await {
+                Ok(accepted) => accepted?.0,
+                Err(_) => continue,
+            };
+
+            if let Err(e) = handle(stream, calendar.as_deref()).await {
+                debug!("Failed to answer a feed request: {}", e);
+            }
+        }
+    }
+
+    /// Fetch the schedule and serialize it to an iCalendar calendar.
+    async fn refresh(&mut self) -> Result<String> {
+        let now = Utc::now();
+        let start = now.checked_sub_days(Days::new(self.past_days));
+        let end = now.checked_add_days(Days::new(self.future_days));
+
+        debug!("Refreshing the feed");
+        let events = self.aurion.get_user_schedule(start, end).await?;
+        let scope = format!("user:{}", self.aurion.menu().user_planning_id());
+
+        Ok(ics::to_calendar_translated(
+            &events,
+            scope,
+            self.aurion.translations(),
+        ))
+    }
+}
+
+/// Answer a single request with the calendar, if any.
+async fn handle(mut stream: TcpStream, calendar: Option<&str>) -> Result<()> {
+    let head = timeout(REQUEST_TIMEOUT, read_head(&mut stream)).await??;
+    let mut words = head.split_whitespace();
+    let method = words.next().unwrap_or_default();
+    let path = words.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+
+    let (status, content_type, body) = match (method, path, calendar) {
+        ("GET" | "HEAD", FEED_PATH, Some(calendar)) => {
+            ("200 OK", "text/calendar; charset=utf-8", calendar)
+        }
+        ("GET" | "HEAD", FEED_PATH, None) => (
+            "503 Service Unavailable",
+            "text/plain; charset=utf-8",
+            "The schedule could not be fetched yet.\n",
+        ),
+        ("GET" | "HEAD", _, _) => ("404 Not Found", "text/plain; charset=utf-8", "Not found.\n"),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "Method not allowed.\n",
+        ),
+    };
+    debug!("{} {} -> {}", method, path, status);
+
+    let mut response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    if method != "HEAD" {
+        response.push_str(body);
+    }
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+
+    Ok(())
+}
+
+/// Read the head of a request, up to the empty line ending it.
+async fn read_head(stream: &mut TcpStream) -> Result<String> {
+    let mut head = Vec::new();
+    let mut buffer = [0; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") && head.len() < MAX_REQUEST_SIZE {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration as ChronoDuration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::time::sleep;
+
+    use super::*;
+    use crate::test_support::{aurion, event, FakeTransport};
+
+    /// Get the feed from the server.
+    async fn get_feed(address: SocketAddr) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        stream
+            .write_all(b"GET /calendar.ics HTTP/1.1\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn keeps_serving_the_last_feed_while_aurion_fails() {
+        let transport = FakeTransport::new(vec![event("42", Utc::now() + ChronoDuration::days(1))]);
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let server = FeedServer::new(aurion(transport.clone()), address)
+            .refresh_interval(Duration::from_millis(10));
+        tokio::spawn(server.run());
+        sleep(Duration::from_millis(100)).await;
+
+        let feed = get_feed(address).await;
+        assert!(feed.starts_with("HTTP/1.1 200 OK"));
+        assert!(feed.contains("BEGIN:VEVENT"));
+
+        transport.set_failing(true);
+        while transport.failures() < 2 {
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(get_feed(address).await, feed);
+    }
+}
@@ -0,0 +1,109 @@
+#![deny(missing_docs)]
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use super::Locale;
+
+const FRENCH_DAYS: [&str; 7] = [
+    "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+];
+const ENGLISH_DAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const FRENCH_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Get the name of the day of the week in the given locale.
+pub fn day_name(weekday: Weekday, locale: Locale) -> &'static str {
+    let index = weekday.num_days_from_monday() as usize;
+    match locale {
+        Locale::French => FRENCH_DAYS[index],
+        Locale::English => ENGLISH_DAYS[index],
+    }
+}
+
+/// Get the name of the month (from 1 to 12) in the given locale.
+///
+/// # Panics
+///
+/// Panics if the month is not between 1 and 12.
+pub fn month_name(month: u32, locale: Locale) -> &'static str {
+    let index = month as usize - 1;
+    match locale {
+        Locale::French => FRENCH_MONTHS[index],
+        Locale::English => ENGLISH_MONTHS[index],
+    }
+}
+
+/// Format a date as its day of the week, day of the month and month.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::format::{format_day, Locale};
+/// use chrono::NaiveDate;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 12).unwrap();
+/// assert_eq!(format_day(date, Locale::French), "lundi 12 février");
+/// assert_eq!(format_day(date, Locale::English), "Monday 12 February");
+/// ```
+pub fn format_day(date: NaiveDate, locale: Locale) -> String {
+    format!(
+        "{} {} {}",
+        day_name(date.weekday(), locale),
+        date.day(),
+        month_name(date.month(), locale)
+    )
+}
+
+/// Format the ISO week number of a date.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::format::{format_week, Locale};
+/// use chrono::NaiveDate;
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 9, 12).unwrap();
+/// assert_eq!(format_week(date, Locale::French), "semaine 37");
+/// assert_eq!(format_week(date, Locale::English), "week 37");
+/// ```
+pub fn format_week(date: NaiveDate, locale: Locale) -> String {
+    let week = date.iso_week().week();
+    match locale {
+        Locale::French => format!("semaine {}", week),
+        Locale::English => format!("week {}", week),
+    }
+}
@@ -0,0 +1,14 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// The language used to format dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    /// French, the language of Aurion.
+    #[default]
+    French,
+
+    /// English.
+    English,
+}
@@ -0,0 +1,10 @@
+mod date;
+mod locale;
+mod translations;
+
+pub use date::day_name;
+pub use date::format_day;
+pub use date::format_week;
+pub use date::month_name;
+pub use locale::Locale;
+pub use translations::Translations;
@@ -0,0 +1,77 @@
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A map translating the labels written by Aurion, such as menu node names,
+/// planning names and subjects, to user-chosen labels.
+///
+/// Labels without a translation are kept as is. The translations are only
+/// applied when rendering, for example by
+/// [`ics::to_calendar_translated`](crate::export::ics::to_calendar_translated),
+/// so the fetched data stays as written by Aurion. The map can be
+/// deserialized from a JSON object, for example to load it from a
+/// configuration file.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::format::Translations;
+///
+/// let translations = Translations::new()
+///     .with("Mathématiques", "Mathematics")
+///     .with("Physique", "Physics");
+///
+/// assert_eq!(translations.translate("Mathématiques"), "Mathematics");
+/// assert_eq!(translations.translate("Chimie"), "Chimie");
+///
+/// let translations: Translations = serde_json::from_str(r#"{"Physique": "Physics"}"#).unwrap();
+/// assert_eq!(translations.translate("Physique"), "Physics");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Translations {
+    labels: HashMap<String, String>,
+}
+
+impl Translations {
+    /// Create an empty translation map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the translation of a label.
+    pub fn with<F: Into<String>, T: Into<String>>(mut self, label: F, translation: T) -> Self {
+        self.insert(label, translation);
+        self
+    }
+
+    /// Add the translation of a label.
+    pub fn insert<F: Into<String>, T: Into<String>>(&mut self, label: F, translation: T) {
+        self.labels.insert(label.into(), translation.into());
+    }
+
+    /// Check whether the map has no translation.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Translate a label, or keep it as is if it has no translation.
+    pub fn translate<'a>(&'a self, label: &'a str) -> &'a str {
+        self.labels
+            .get(label.trim())
+            .map(String::as_str)
+            .unwrap_or(label)
+    }
+}
+
+impl<F: Into<String>, T: Into<String>> FromIterator<(F, T)> for Translations {
+    fn from_iter<I: IntoIterator<Item = (F, T)>>(iter: I) -> Self {
+        let mut translations = Self::new();
+        for (label, translation) in iter {
+            translations.insert(label, translation);
+        }
+        translations
+    }
+}
@@ -1,9 +1,49 @@
+#![allow(clippy::module_inception)]
+
 mod aurion;
+mod aurion_api;
+mod aurion_builder;
+mod auth_method;
+mod cache;
+mod capabilities;
+#[cfg(feature = "keyring")]
+mod credentials;
 mod default;
+pub mod document;
+mod error;
 pub mod event;
-mod menu;
+pub mod export;
+#[cfg(feature = "feed-server")]
+pub mod feed_server;
+pub mod format;
+pub mod menu;
+mod mock_aurion;
 mod pages;
+pub mod profile;
+mod read_only_aurion;
+#[cfg(feature = "refresher")]
+mod refresher;
+pub mod roster;
 pub mod schedule;
+pub mod stages;
+pub mod sync;
+mod telemetry;
+#[cfg(test)]
+mod test_support;
+pub mod transport;
+mod two_factor;
+pub mod uid;
 mod utils;
+pub mod watch;
 
 pub use aurion::Aurion;
+pub use aurion_api::AurionApi;
+pub use aurion_builder::AurionBuilder;
+pub use auth_method::AuthMethod;
+pub use capabilities::Capabilities;
+pub use error::AurionError;
+pub use mock_aurion::MockAurion;
+pub use read_only_aurion::ReadOnlyAurion;
+#[cfg(feature = "refresher")]
+pub use refresher::RefresherHandle;
+pub use two_factor::TwoFactor;
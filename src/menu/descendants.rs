@@ -0,0 +1,47 @@
+#![deny(missing_docs)]
+
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+
+use super::Node;
+
+/// An iterator over nodes of the menu tree and their descendants, either
+/// depth-first or breadth-first.
+///
+/// Created by [`Node::descendants`], [`Node::descendants_breadth_first`],
+/// [`Menu::iter`](super::Menu::iter) and
+/// [`Menu::iter_breadth_first`](super::Menu::iter_breadth_first).
+pub struct Descendants {
+    pending: VecDeque<Arc<RwLock<Node>>>,
+    depth_first: bool,
+}
+
+impl Descendants {
+    /// Create an iterator walking the given nodes and their descendants.
+    pub(crate) fn new(nodes: Vec<Arc<RwLock<Node>>>, depth_first: bool) -> Self {
+        Self {
+            pending: nodes.into(),
+            depth_first,
+        }
+    }
+}
+
+impl Iterator for Descendants {
+    type Item = Arc<RwLock<Node>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.pending.pop_front()?;
+
+        let children = node.read().unwrap().get_children().to_vec();
+        if self.depth_first {
+            // Visit the children before the pending siblings, in order
+            for child in children.into_iter().rev() {
+                self.pending.push_front(child);
+            }
+        } else {
+            self.pending.extend(children);
+        }
+
+        Some(node)
+    }
+}
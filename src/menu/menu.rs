@@ -1,16 +1,46 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+#![deny(missing_docs)]
 
-use super::Node;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
 
+use anyhow::{Error, Result};
+use tracing::error;
+
+use super::{Descendants, MenuTree, MenuTreeNode, Node};
+
+/// Aurion's sidebar menu tree.
+///
+/// The menu is usually discovered lazily by [`Aurion`](crate::Aurion), but it
+/// can also be built manually when the tree is already known, to skip the
+/// discovery requests entirely.
+///
+/// Nodes are indexed by their menu id: the `submenu_*` class of a parent
+/// node, or the `item_*` class of a leaf node. The id of a leaf node itself
+/// is the id of the page it opens.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::menu::Menu;
+///
+/// let mut menu = Menu::new(275805, "submenu_291906", "item_1", "submenu_299102");
+/// menu.add_child_node("submenu_291906", "item_1", "1_3", "My planning")
+///     .unwrap();
+///
+/// assert!(menu.is_node_loaded("submenu_291906"));
+/// ```
 pub struct Menu {
     language_code: u32,
     schooling_id: String,
     user_planning_id: String,
     groups_planning_id: String,
-    nodes: HashMap<String, Rc<RefCell<Node>>>,
+    nodes: HashMap<String, Arc<RwLock<Node>>>,
 }
 
 impl Menu {
+    /// Create a new menu with the schooling and groups planning root nodes.
     pub fn new<S: Into<String>, U: Into<String>, G: Into<String>>(
         language_code: u32,
         schooling_id: S,
@@ -21,20 +51,25 @@ impl Menu {
         let user_planning_id = user_planning_id.into();
         let groups_planning_id = groups_planning_id.into();
 
-        let schooling_node = Rc::new(RefCell::new(Node::new(
+        let schooling_node = Arc::new(RwLock::new(Node::new(
             schooling_id.clone(),
             "Schooling",
             None,
         )));
-        let groups_planning_node = Rc::new(RefCell::new(Node::new(
+        let groups_planning_node = Arc::new(RwLock::new(Node::new(
             groups_planning_id.clone(),
             "Groups",
             None,
         )));
 
         let mut nodes = HashMap::new();
-        nodes.insert(schooling_id.clone(), Rc::clone(&schooling_node));
-        nodes.insert(groups_planning_id.clone(), Rc::clone(&groups_planning_node));
+        nodes.insert(schooling_id.clone(), Arc::clone(&schooling_node));
+        if !groups_planning_id.is_empty() {
+            nodes.insert(
+                groups_planning_id.clone(),
+                Arc::clone(&groups_planning_node),
+            );
+        }
         Self {
             language_code,
             schooling_id,
@@ -44,35 +79,352 @@ impl Menu {
         }
     }
 
+    /// Get the language code of Aurion.
     pub fn language_code(&self) -> u32 {
         self.language_code
     }
 
+    /// Get the menu id of the schooling node.
     pub fn schooling_id(&self) -> &str {
         &self.schooling_id
     }
 
+    /// Get the menu id of the user planning node.
     pub fn user_planning_id(&self) -> &str {
         &self.user_planning_id
     }
 
+    /// Get the menu id of the groups planning node.
     pub fn groups_planning_id(&self) -> &str {
         &self.groups_planning_id
     }
 
-    pub fn add_node(&mut self, id: String, node: Rc<RefCell<Node>>) {
+    /// Set the menu id of the user planning node, for example once it has
+    /// been discovered among the loaded nodes.
+    pub fn set_user_planning_id<U: Into<String>>(&mut self, user_planning_id: U) {
+        self.user_planning_id = user_planning_id.into();
+    }
+
+    /// Index a node by its menu id.
+    pub fn add_node(&mut self, id: String, node: Arc<RwLock<Node>>) {
         self.nodes.insert(id, node);
     }
 
-    pub fn get_menu_node<T: Into<String>>(&self, menu_id: T) -> Option<Rc<RefCell<Node>>> {
+    /// Create a node and add it as a child of the node with the given parent
+    /// menu id.
+    ///
+    /// `menu_id` is the id used to index the node, and `id` the id of the
+    /// node itself, which differs for leaf nodes (see [`Menu`]).
+    pub fn add_child_node<P: Into<String>, M: Into<String>, I: Into<String>, N: Into<String>>(
+        &mut self,
+        parent_menu_id: P,
+        menu_id: M,
+        id: I,
+        name: N,
+    ) -> Result<Arc<RwLock<Node>>> {
+        let parent_menu_id = parent_menu_id.into();
+        let parent = match self.nodes.get(&parent_menu_id) {
+            Some(parent) => Arc::clone(parent),
+            None => {
+                let message = format!("Node {} not found", parent_menu_id);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        let child = Arc::new(RwLock::new(Node::new(id, name, Some(Arc::clone(&parent)))));
+        parent.write().unwrap().add_child(Arc::clone(&child));
+        self.nodes.insert(menu_id.into(), Arc::clone(&child));
+
+        Ok(child)
+    }
+
+    /// Get the node with the given menu id.
+    pub fn get_menu_node<T: Into<String>>(&self, menu_id: T) -> Option<Arc<RwLock<Node>>> {
         self.nodes.get(&menu_id.into()).cloned()
     }
 
+    /// Iterate over the loaded nodes depth-first, starting from the
+    /// schooling node then the groups planning node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::menu::Menu;
+    ///
+    /// let mut menu = Menu::new(275805, "submenu_291906", "item_1", "submenu_299102");
+    /// menu.add_child_node("submenu_291906", "item_1", "1_3", "My planning")
+    ///     .unwrap();
+    ///
+    /// let ids = menu.iter().map(|node| node.read().unwrap().id.clone()).collect::<Vec<_>>();
+    /// assert_eq!(ids, ["submenu_291906", "1_3", "submenu_299102"]);
+    ///
+    /// let ids = menu
+    ///     .iter_breadth_first()
+    ///     .map(|node| node.read().unwrap().id.clone())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(ids, ["submenu_291906", "submenu_299102", "1_3"]);
+    /// ```
+    pub fn iter(&self) -> Descendants {
+        Descendants::new(self.roots(), true)
+    }
+
+    /// Iterate over the loaded nodes breadth-first, starting from the
+    /// schooling node then the groups planning node.
+    pub fn iter_breadth_first(&self) -> Descendants {
+        Descendants::new(self.roots(), false)
+    }
+
+    /// Get the root nodes: the schooling node and the groups planning node.
+    fn roots(&self) -> Vec<Arc<RwLock<Node>>> {
+        [&self.schooling_id, &self.groups_planning_id]
+            .into_iter()
+            .filter_map(|menu_id| self.nodes.get(menu_id).cloned())
+            .collect()
+    }
+
+    /// Get the menu id the given node is indexed by.
+    pub fn menu_id_of(&self, node: &Arc<RwLock<Node>>) -> Option<&str> {
+        self.nodes
+            .iter()
+            .find(|(_, indexed)| Arc::ptr_eq(indexed, node))
+            .map(|(menu_id, _)| menu_id.as_str())
+    }
+
+    /// Check whether the schooling and user planning menu ids are known.
+    ///
+    /// The groups planning menu id is optional, as some accounts have no
+    /// groups branch, see [`Menu::has_group_plannings`].
+    pub fn has_menu_ids(&self) -> bool {
+        !self.schooling_id.is_empty() && !self.user_planning_id.is_empty()
+    }
+
+    /// Check whether the account has a groups planning branch, that is
+    /// whether the groups planning menu id is known.
+    pub fn has_group_plannings(&self) -> bool {
+        !self.groups_planning_id.is_empty()
+    }
+
+    /// Get all the nodes of the menu tree loaded so far.
+    pub fn nodes(&self) -> impl Iterator<Item = &Arc<RwLock<Node>>> {
+        self.nodes.values()
+    }
+
+    /// Find the loaded nodes with the given label.
+    ///
+    /// Labels are compared case-insensitively and without the "Planning"
+    /// and "Plannings" words, which are stripped from the node labels when
+    /// the menu is loaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::menu::Menu;
+    ///
+    /// let mut menu = Menu::new(275805, "submenu_291906", "item_1", "submenu_299102");
+    /// menu.add_child_node("submenu_299102", "submenu_1", "submenu_1", "Brest")
+    ///     .unwrap();
+    /// menu.add_child_node("submenu_1", "item_2", "2_3", "CIR 2 Brest")
+    ///     .unwrap();
+    ///
+    /// let nodes = menu.find_by_name("Planning CIR 2 brest");
+    /// assert_eq!(nodes[0].read().unwrap().id, "2_3");
+    ///
+    /// let nodes = menu.find_by_path(["Groups", "Brest", "CIR 2 Brest"]);
+    /// assert_eq!(nodes.len(), 1);
+    /// assert!(menu.find_by_path(["Schooling", "CIR 2 Brest"]).is_empty());
+    /// ```
+    pub fn find_by_name<N: AsRef<str>>(&self, name: N) -> Vec<Arc<RwLock<Node>>> {
+        let name = normalize_name(name.as_ref());
+        self.find(|node| normalize_name(&node.read().unwrap().name) == name)
+    }
+
+    /// Find the loaded nodes at the end of the given path of labels.
+    ///
+    /// The last label of the path is the label of the node, and the previous
+    /// ones the labels of its ancestors, up to any depth. Labels are compared
+    /// like in [`Menu::find_by_name`]. The root nodes are labelled
+    /// "Schooling" and "Groups".
+    pub fn find_by_path<I: IntoIterator<Item = N>, N: AsRef<str>>(
+        &self,
+        path: I,
+    ) -> Vec<Arc<RwLock<Node>>> {
+        let path = path
+            .into_iter()
+            .map(|name| normalize_name(name.as_ref()))
+            .collect::<Vec<String>>();
+        if path.is_empty() {
+            return Vec::new();
+        }
+
+        self.find(|node| {
+            // Walk up the ancestors while matching the path backwards
+            let mut current = Some(Arc::clone(node));
+            for name in path.iter().rev() {
+                let node = match current {
+                    Some(node) => node,
+                    None => return false,
+                };
+                if normalize_name(&node.read().unwrap().name) != *name {
+                    return false;
+                }
+                current = node.read().unwrap().parent.clone();
+            }
+            true
+        })
+    }
+
+    /// Find the loaded nodes matching the given predicate, sorted by id.
+    fn find<P: Fn(&Arc<RwLock<Node>>) -> bool>(&self, predicate: P) -> Vec<Arc<RwLock<Node>>> {
+        let mut nodes = self
+            .nodes
+            .values()
+            .filter(|node| predicate(node))
+            .cloned()
+            .collect::<Vec<_>>();
+        nodes.sort_by(|a, b| a.read().unwrap().id.cmp(&b.read().unwrap().id));
+        nodes
+    }
+
+    /// Get a serializable snapshot of the menu tree loaded so far.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::menu::Menu;
+    ///
+    /// let mut menu = Menu::new(275805, "submenu_291906", "item_1", "submenu_299102");
+    /// menu.add_child_node("submenu_291906", "item_1", "1_3", "My planning")
+    ///     .unwrap();
+    ///
+    /// let tree = menu.to_tree();
+    /// assert_eq!(tree.roots[0].children[0].menu_id, "item_1");
+    /// assert_eq!(tree.roots[0].children[0].id, "1_3");
+    ///
+    /// let json = serde_json::to_string(&tree).unwrap();
+    /// assert!(json.contains("My planning"));
+    /// ```
+    pub fn to_tree(&self) -> MenuTree {
+        // Nodes only know their own id, so map them back to their menu id
+        let menu_ids = self
+            .nodes
+            .iter()
+            .map(|(menu_id, node)| (Arc::as_ptr(node), menu_id.clone()))
+            .collect::<HashMap<_, _>>();
+
+        let roots = self
+            .roots()
+            .iter()
+            .map(|node| tree_node(node, &menu_ids))
+            .collect();
+
+        MenuTree {
+            language_code: self.language_code,
+            schooling_id: self.schooling_id.clone(),
+            user_planning_id: self.user_planning_id.clone(),
+            groups_planning_id: self.groups_planning_id.clone(),
+            roots,
+        }
+    }
+
+    /// Build a menu from a snapshot of a menu tree, see [`Menu::to_tree`].
+    pub fn from_tree(tree: &MenuTree) -> Result<Self> {
+        let mut menu = Self::new(
+            tree.language_code,
+            tree.schooling_id.clone(),
+            tree.user_planning_id.clone(),
+            tree.groups_planning_id.clone(),
+        );
+
+        // Add the descendants of the root nodes breadth-first, so that parents
+        // are added before their children and siblings keep their order
+        let mut pending = tree
+            .roots
+            .iter()
+            .flat_map(|root| root.children.iter().map(move |child| (root, child)))
+            .collect::<VecDeque<_>>();
+        while let Some((parent, node)) = pending.pop_front() {
+            menu.add_child_node(&parent.menu_id, &node.menu_id, &node.id, &node.name)?;
+            pending.extend(node.children.iter().map(|child| (node, child)));
+        }
+
+        Ok(menu)
+    }
+
+    /// Save the menu tree loaded so far to the given file, as JSON.
+    ///
+    /// The menu rarely changes within a school year, so loading it back with
+    /// [`Menu::load`] and setting it with
+    /// [`Aurion::menu_mut`](crate::Aurion::menu_mut) skips the requests to
+    /// discover the menu on the next runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::menu::Menu;
+    ///
+    /// let mut menu = Menu::new(275805, "submenu_291906", "item_1", "submenu_299102");
+    /// menu.add_child_node("submenu_291906", "item_1", "1_3", "My planning")
+    ///     .unwrap();
+    ///
+    /// let path = std::env::temp_dir().join("aurion_rs_menu.json");
+    /// menu.save(&path).unwrap();
+    ///
+    /// let menu = Menu::load(&path).unwrap();
+    /// assert_eq!(menu.get_menu_node("item_1").unwrap().read().unwrap().id, "1_3");
+    /// assert!(menu.is_node_loaded("submenu_291906"));
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let json = serde_json::to_string(&self.to_tree())?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load a menu tree saved with [`Menu::save`] from the given file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let json = fs::read_to_string(path)?;
+        let tree: MenuTree = serde_json::from_str(&json)?;
+        Self::from_tree(&tree)
+    }
+
+    /// Check if the node with the given menu id has been loaded.
     pub fn is_node_loaded<T: Into<String>>(&self, menu_id: T) -> bool {
         let menu_id = menu_id.into();
         match self.nodes.get(&menu_id) {
-            Some(node) => node.borrow().is_loaded(),
+            Some(node) => node.read().unwrap().is_loaded(),
             None => false,
         }
     }
 }
+
+/// Convert a node and its children to tree nodes.
+fn tree_node(
+    node: &Arc<RwLock<Node>>,
+    menu_ids: &HashMap<*const RwLock<Node>, String>,
+) -> MenuTreeNode {
+    let node_ref = node.read().unwrap();
+    MenuTreeNode {
+        menu_id: menu_ids
+            .get(&Arc::as_ptr(node))
+            .cloned()
+            .unwrap_or_else(|| node_ref.id.clone()),
+        id: node_ref.id.clone(),
+        name: node_ref.name.clone(),
+        children: node_ref
+            .get_children()
+            .iter()
+            .map(|child| tree_node(child, menu_ids))
+            .collect(),
+    }
+}
+
+/// Normalize a node label to compare it: lowercase and without the
+/// "Planning" and "Plannings" words.
+fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+        .split_whitespace()
+        .filter(|word| *word != "planning" && *word != "plannings")
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
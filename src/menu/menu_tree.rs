@@ -0,0 +1,39 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// A serializable snapshot of the loaded menu tree, see
+/// [`Menu::to_tree`](super::Menu::to_tree).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MenuTree {
+    /// The language code of Aurion.
+    pub language_code: u32,
+
+    /// The menu id of the schooling node.
+    pub schooling_id: String,
+
+    /// The menu id of the user planning node.
+    pub user_planning_id: String,
+
+    /// The menu id of the groups planning node.
+    pub groups_planning_id: String,
+
+    /// The root nodes: the schooling node and the groups planning node.
+    pub roots: Vec<MenuTreeNode>,
+}
+
+/// A node of a [`MenuTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MenuTreeNode {
+    /// The menu id the node is indexed by.
+    pub menu_id: String,
+
+    /// The id of the node.
+    pub id: String,
+
+    /// The label of the node.
+    pub name: String,
+
+    /// The loaded child nodes.
+    pub children: Vec<MenuTreeNode>,
+}
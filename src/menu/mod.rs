@@ -1,5 +1,10 @@
+mod descendants;
 mod menu;
+mod menu_tree;
 mod node;
 
+pub use descendants::Descendants;
+pub use menu::Menu;
+pub use menu_tree::MenuTree;
+pub use menu_tree::MenuTreeNode;
 pub use node::Node;
-pub use menu::Menu;
\ No newline at end of file
@@ -1,39 +1,67 @@
-use std::{rc::Rc, cell::RefCell};
+#![deny(missing_docs)]
 
+use std::sync::{Arc, RwLock};
+
+use super::Descendants;
+
+/// A node of Aurion's sidebar menu tree.
 #[derive(Debug)]
 pub struct Node {
+    /// The id of the node: the `submenu_*` id of a parent node, or the id of
+    /// the page opened by a leaf node.
     pub id: String,
+
+    /// The label of the node.
     pub name: String,
-    pub children: Vec<Rc<RefCell<Node>>>,
-    pub parent: Option<Rc<RefCell<Node>>>,
+
+    /// The loaded child nodes.
+    pub children: Vec<Arc<RwLock<Node>>>,
+
+    /// The parent node, if any.
+    pub parent: Option<Arc<RwLock<Node>>>,
 }
 
 impl Node {
-    pub fn new<I: Into<String>, N: Into<String>>(id: I, name: N, parent: Option<Rc<RefCell<Node>>>) -> Self {
+    /// Create a new node.
+    pub fn new<I: Into<String>, N: Into<String>>(
+        id: I,
+        name: N,
+        parent: Option<Arc<RwLock<Node>>>,
+    ) -> Self {
         Self {
             id: id.into(),
             name: name.into(),
             children: Vec::new(),
             parent: match parent {
-                Some(node) => Some(Rc::clone(&node)),
+                Some(node) => Some(Arc::clone(&node)),
                 None => None,
             },
         }
     }
 
     /// Add a child to the current node.
-    pub fn add_child(&mut self, child: Rc<RefCell<Node>>) {
+    pub fn add_child(&mut self, child: Arc<RwLock<Node>>) {
         self.children.push(child);
     }
 
     /// Get the children of the current node.
-    pub fn get_children(&self) -> &Vec<Rc<RefCell<Node>>> {
+    pub fn get_children(&self) -> &Vec<Arc<RwLock<Node>>> {
         &self.children
     }
 
+    /// Iterate over the loaded descendants of the node, depth-first.
+    pub fn descendants(&self) -> Descendants {
+        Descendants::new(self.children.clone(), true)
+    }
+
+    /// Iterate over the loaded descendants of the node, breadth-first.
+    pub fn descendants_breadth_first(&self) -> Descendants {
+        Descendants::new(self.children.clone(), false)
+    }
+
     /// Check if the node has been loaded
     pub fn is_loaded(&self) -> bool {
-        !(&self.id.starts_with("submenu_") ^ !self.children.is_empty())
+        !(self.id.starts_with("submenu_") ^ !self.children.is_empty())
     }
 
     /// Check if the node is a leaf
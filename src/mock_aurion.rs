@@ -0,0 +1,426 @@
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+
+use anyhow::{Error, Result};
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::aurion_api::AurionApi;
+use crate::document::{Document, ReportCard};
+use crate::event::{Event, EventDetails, EventId, Exam};
+use crate::profile::Profile;
+use crate::roster::Student;
+use crate::schedule::{dedup, ClassGroup, ScheduleRequest, Teacher};
+use crate::stages::{Internship, InternshipOffer};
+use crate::utils::{day_range, shift_weeks, today, week_range};
+
+/// The timezone in which ranges of days are converted.
+const TIMEZONE: Tz = chrono_tz::Europe::Paris;
+
+/// A programmable implementation of [`AurionApi`], to test code using
+/// Aurion without network.
+///
+/// Each fetch returns the programmed response, schedules being filtered to
+/// the events overlapping the requested range. Ranges of days are converted
+/// with the `Europe/Paris` timezone. Fetching a response that was
+/// not programmed fails.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::ScheduleRequest;
+/// use aurion_rs::{AurionApi, MockAurion};
+/// use chrono::{TimeZone, Utc};
+///
+/// async fn count_courses<A: AurionApi>(aurion: &A) -> anyhow::Result<usize> {
+///     let schedule = aurion.get_user_schedule(None, None).await?;
+///     Ok(schedule
+///         .iter()
+///         .filter(|event| event.kind == EventKind::Course)
+///         .count())
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["B101".into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+///
+/// let aurion = MockAurion::new().with_user_schedule(vec![event]);
+/// assert_eq!(count_courses(&aurion).await.unwrap(), 1);
+///
+/// // The fetches can be spawned on a multi-threaded runtime
+/// let spawned = aurion.clone();
+/// let count = tokio::spawn(async move { count_courses(&spawned).await });
+/// assert_eq!(count.await.unwrap().unwrap(), 1);
+///
+/// // Requests are answered from the programmed schedules
+/// let request = ScheduleRequest::new().kinds([EventKind::Exam]);
+/// assert!(aurion.fetch_schedule(&request).await.unwrap().is_empty());
+///
+/// // Responses that were not programmed fail
+/// assert!(aurion.get_teachers().await.is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockAurion {
+    user_schedule: Option<Vec<Event>>,
+    class_groups: HashMap<String, Vec<ClassGroup>>,
+    group_schedules: HashMap<u32, Vec<Event>>,
+    room_schedules: HashMap<u32, Vec<Event>>,
+    teachers: Option<Vec<Teacher>>,
+    teacher_schedules: HashMap<u32, Vec<Event>>,
+    event_details: HashMap<EventId, EventDetails>,
+    exams: Option<Vec<Exam>>,
+    profile: Option<Profile>,
+    rosters: HashMap<u32, Vec<Student>>,
+    documents: Option<Vec<Document>>,
+    document_files: HashMap<String, Bytes>,
+    report_cards: Option<Vec<ReportCard>>,
+    report_card_files: HashMap<String, Bytes>,
+    school_certificate: Option<Bytes>,
+    internship_offers: Option<Vec<InternshipOffer>>,
+    internships: Option<Vec<Internship>>,
+}
+
+impl MockAurion {
+    /// Create a mock without any programmed response.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Program the user's schedule.
+    pub fn with_user_schedule(mut self, events: Vec<Event>) -> Self {
+        self.user_schedule = Some(events);
+        self
+    }
+
+    /// Program the class groups designated by class_group_id.
+    pub fn with_class_groups<I: Into<String>>(
+        mut self,
+        class_group_id: I,
+        groups: Vec<ClassGroup>,
+    ) -> Self {
+        self.class_groups.insert(class_group_id.into(), groups);
+        self
+    }
+
+    /// Program the schedule of the class group with the given id.
+    pub fn with_group_schedule(mut self, group_id: u32, events: Vec<Event>) -> Self {
+        self.group_schedules.insert(group_id, events);
+        self
+    }
+
+    /// Program the schedule of the room with the given id.
+    pub fn with_room_schedule(mut self, room_id: u32, events: Vec<Event>) -> Self {
+        self.room_schedules.insert(room_id, events);
+        self
+    }
+
+    /// Program the teachers whose planning can be fetched.
+    pub fn with_teachers(mut self, teachers: Vec<Teacher>) -> Self {
+        self.teachers = Some(teachers);
+        self
+    }
+
+    /// Program the schedule of the teacher with the given id.
+    pub fn with_teacher_schedule(mut self, teacher_id: u32, events: Vec<Event>) -> Self {
+        self.teacher_schedules.insert(teacher_id, events);
+        self
+    }
+
+    /// Program the details of an event.
+    pub fn with_event_details(mut self, details: EventDetails) -> Self {
+        self.event_details.insert(details.id.clone(), details);
+        self
+    }
+
+    /// Program the exams listed in the exams page.
+    pub fn with_exams(mut self, exams: Vec<Exam>) -> Self {
+        self.exams = Some(exams);
+        self
+    }
+
+    /// Program the personal information of the user.
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Program the students of the class group with the given id.
+    pub fn with_roster(mut self, group_id: u32, students: Vec<Student>) -> Self {
+        self.rosters.insert(group_id, students);
+        self
+    }
+
+    /// Program the administrative documents that can be downloaded.
+    pub fn with_documents(mut self, documents: Vec<Document>) -> Self {
+        self.documents = Some(documents);
+        self
+    }
+
+    /// Program the content of the administrative document with the given id.
+    pub fn with_document_file<I: Into<String>>(mut self, document_id: I, file: Bytes) -> Self {
+        self.document_files.insert(document_id.into(), file);
+        self
+    }
+
+    /// Program the grade bulletins published for the user.
+    pub fn with_report_cards(mut self, report_cards: Vec<ReportCard>) -> Self {
+        self.report_cards = Some(report_cards);
+        self
+    }
+
+    /// Program the content of the grade bulletin of the given semester.
+    pub fn with_report_card_file<I: Into<String>>(mut self, semester: I, file: Bytes) -> Self {
+        self.report_card_files.insert(semester.into(), file);
+        self
+    }
+
+    /// Program the content of the school certificate of the user.
+    pub fn with_school_certificate(mut self, file: Bytes) -> Self {
+        self.school_certificate = Some(file);
+        self
+    }
+
+    /// Program the internship offers published in Aurion.
+    pub fn with_internship_offers(mut self, offers: Vec<InternshipOffer>) -> Self {
+        self.internship_offers = Some(offers);
+        self
+    }
+
+    /// Program the internships of the user.
+    pub fn with_internships(mut self, internships: Vec<Internship>) -> Self {
+        self.internships = Some(internships);
+        self
+    }
+}
+
+impl AurionApi for MockAurion {
+    async fn get_class_groups(&self, class_group_id: &str) -> Result<Vec<ClassGroup>> {
+        programmed(
+            self.class_groups.get(class_group_id).cloned(),
+            format!("class groups {}", class_group_id),
+        )
+    }
+
+    async fn get_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        let events = programmed(
+            self.group_schedules.get(&group.id),
+            format!("schedule of group {}", group.id),
+        )?;
+        let mut events = in_range(events, start, end);
+        for event in &mut events {
+            event.group_id = Some(group.group_id());
+        }
+        Ok(events)
+    }
+
+    async fn get_combined_schedule(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        let mut events = Vec::new();
+        for group in groups {
+            let schedule = programmed(
+                self.group_schedules.get(&group.id),
+                format!("schedule of group {}", group.id),
+            )?;
+            events.extend(in_range(schedule, start, end));
+        }
+        Ok(dedup(events))
+    }
+
+    async fn get_group_schedules(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<HashMap<ClassGroup, Vec<Event>>> {
+        let mut schedules = HashMap::with_capacity(groups.len());
+        for group in groups {
+            let schedule = self.get_group_schedule(group, start, end).await?;
+            schedules.insert(group.clone(), schedule);
+        }
+        Ok(schedules)
+    }
+
+    async fn get_room_schedule(
+        &self,
+        room_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        let events = programmed(
+            self.room_schedules.get(&room_id),
+            format!("schedule of room {}", room_id),
+        )?;
+        Ok(in_range(events, start, end))
+    }
+
+    async fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        programmed(self.teachers.clone(), "teachers".to_string())
+    }
+
+    async fn get_teacher_schedule(
+        &self,
+        teacher_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        let events = programmed(
+            self.teacher_schedules.get(&teacher_id),
+            format!("schedule of teacher {}", teacher_id),
+        )?;
+        Ok(in_range(events, start, end))
+    }
+
+    async fn get_event_details(&self, event_id: &EventId) -> Result<EventDetails> {
+        programmed(
+            self.event_details.get(event_id).cloned(),
+            format!("details of event {}", event_id),
+        )
+    }
+
+    async fn get_exams(&self) -> Result<Vec<Exam>> {
+        programmed(self.exams.clone(), "exams".to_string())
+    }
+
+    async fn get_profile(&self) -> Result<Profile> {
+        programmed(self.profile.clone(), "profile".to_string())
+    }
+
+    async fn get_roster(&self, group_id: u32) -> Result<Vec<Student>> {
+        programmed(
+            self.rosters.get(&group_id).cloned(),
+            format!("roster of group {}", group_id),
+        )
+    }
+
+    async fn list_documents(&self) -> Result<Vec<Document>> {
+        programmed(self.documents.clone(), "documents".to_string())
+    }
+
+    async fn download_document(&self, document_id: &str) -> Result<Bytes> {
+        programmed(
+            self.document_files.get(document_id).cloned(),
+            format!("document {}", document_id),
+        )
+    }
+
+    async fn list_report_cards(&self) -> Result<Vec<ReportCard>> {
+        programmed(self.report_cards.clone(), "report cards".to_string())
+    }
+
+    async fn download_report_card(&self, semester: &str) -> Result<Bytes> {
+        programmed(
+            self.report_card_files.get(semester).cloned(),
+            format!("report card of semester {}", semester),
+        )
+    }
+
+    async fn download_school_certificate(&self) -> Result<Bytes> {
+        programmed(
+            self.school_certificate.clone(),
+            "school certificate".to_string(),
+        )
+    }
+
+    async fn get_internship_offers(&self) -> Result<Vec<InternshipOffer>> {
+        programmed(
+            self.internship_offers.clone(),
+            "internship offers".to_string(),
+        )
+    }
+
+    async fn get_internship_offer(&self, offer_id: &str) -> Result<InternshipOffer> {
+        let offer = self
+            .internship_offers
+            .iter()
+            .flatten()
+            .find(|offer| offer.id == offer_id)
+            .cloned();
+        programmed(offer, format!("internship offer {}", offer_id))
+    }
+
+    async fn get_internships(&self) -> Result<Vec<Internship>> {
+        programmed(self.internships.clone(), "internships".to_string())
+    }
+
+    async fn get_user_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        let events = programmed(self.user_schedule.as_ref(), "user schedule".to_string())?;
+        Ok(in_range(events, start, end))
+    }
+
+    async fn get_user_schedule_day(&self, date: NaiveDate) -> Result<Vec<Event>> {
+        let (start, end) = day_range(date, TIMEZONE);
+        self.get_user_schedule(Some(start), Some(end)).await
+    }
+
+    async fn get_user_schedule_today(&self) -> Result<Vec<Event>> {
+        self.get_user_schedule_day(today(TIMEZONE)).await
+    }
+
+    async fn get_user_schedule_week(&self, week_offset: i64) -> Result<Vec<Event>> {
+        let date = shift_weeks(today(TIMEZONE), week_offset)
+            .ok_or_else(|| Error::msg(format!("Week offset {} is out of range", week_offset)))?;
+        let (start, end) = week_range(date, TIMEZONE);
+        self.get_user_schedule(Some(start), Some(end)).await
+    }
+
+    async fn fetch_schedule(&self, request: &ScheduleRequest) -> Result<Vec<Event>> {
+        let (start, end) = request.range.resolve(TIMEZONE);
+        let events = match &request.group {
+            Some(group) => self.get_group_schedule(group, start, end).await?,
+            None => self.get_user_schedule(start, end).await?,
+        };
+        Ok(request.filter(events))
+    }
+}
+
+/// Get a programmed response, or fail if it was not programmed.
+fn programmed<R>(response: Option<R>, name: String) -> Result<R> {
+    response.ok_or_else(|| Error::msg(format!("MockAurion: no {} programmed", name)))
+}
+
+/// Get the events overlapping the given range.
+fn in_range(
+    events: &[Event],
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+) -> Vec<Event> {
+    events
+        .iter()
+        .filter(|event| start.is_none_or(|start| event.end > start))
+        .filter(|event| end.is_none_or(|end| event.start < end))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,22 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// The personal information of the logged in user.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    /// The full name of the user, first name first.
+    pub name: String,
+
+    /// The student number of the user.
+    pub student_number: Option<String>,
+
+    /// The promotion of the user, for example "CIR3".
+    pub promotion: Option<String>,
+
+    /// The email address of the user.
+    pub email: Option<String>,
+
+    /// The postal address of the user.
+    pub address: Option<String>,
+}
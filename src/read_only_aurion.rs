@@ -0,0 +1,372 @@
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use futures_util::Stream;
+
+use crate::aurion::Aurion;
+use crate::capabilities::Capabilities;
+use crate::document::{Document, ReportCard};
+use crate::event::{Event, EventDetails, EventId, Exam};
+use crate::menu::Node;
+use crate::profile::Profile;
+use crate::roster::Student;
+use crate::schedule::{ClassGroup, ScheduleRequest, Teacher, UserPlanning, UserPlanningSelector};
+use crate::stages::{Internship, InternshipOffer};
+use crate::transport::{ReqwestTransport, Transport};
+
+/// A restricted Aurion session exposing only the fetching APIs.
+///
+/// The wrapped session can only navigate the menu and read plannings: it
+/// can't log in again or submit anything that would modify the account.
+/// This makes it suitable to hand a logged in session to less trusted code,
+/// as the underlying [`Aurion`] can't be accessed back.
+///
+/// # Example
+///
+/// ```rust
+/// # use aurion_rs::{Aurion, ReadOnlyAurion};
+/// # async fn run() -> anyhow::Result<()> {
+/// #     let mut aurion = Aurion::new(
+/// #         275805,
+/// #         "submenu_291906",
+/// #         "1_3",
+/// #         "submenu_299102",
+/// #         "https://web.isen-ouest.fr/webAurion/",
+/// #     );
+/// aurion.login("username", "password").await?;
+///
+/// let mut aurion: ReadOnlyAurion = aurion.into_read_only();
+/// let schedule = aurion.get_user_schedule(None, None).await?;
+/// #     Ok(())
+/// # }
+/// ```
+pub struct ReadOnlyAurion<T: Transport = ReqwestTransport> {
+    inner: Aurion<T>,
+}
+
+impl<T: Transport> ReadOnlyAurion<T> {
+    /// Restrict the given Aurion session.
+    pub fn new(aurion: Aurion<T>) -> Self {
+        Self { inner: aurion }
+    }
+
+    /// Get the timezone of the school.
+    ///
+    /// See [`Aurion::timezone`].
+    pub fn timezone(&self) -> Tz {
+        self.inner.timezone()
+    }
+
+    /// Get the menu child nodes of the given menu id.
+    ///
+    /// See [`Aurion::get_menu_child_nodes`].
+    pub async fn get_menu_child_nodes<I: Into<String>>(
+        &self,
+        menu_id: I,
+    ) -> Result<Vec<Arc<RwLock<Node>>>> {
+        self.inner.get_menu_child_nodes(menu_id).await
+    }
+
+    /// Load the given menu nodes into the menu tree.
+    ///
+    /// See [`Aurion::load_menu_nodes`].
+    pub async fn load_menu_nodes<I: Into<String>, V: Into<Vec<I>>>(
+        &self,
+        menu_nodes: V,
+    ) -> Result<()> {
+        self.inner.load_menu_nodes(menu_nodes).await
+    }
+
+    /// Load the whole menu tree down to the given depth.
+    ///
+    /// See [`Aurion::load_full_menu`].
+    pub async fn load_full_menu(&self, max_depth: usize) -> Result<()> {
+        self.inner.load_full_menu(max_depth).await
+    }
+
+    /// Probe the features the logged in account can access.
+    ///
+    /// See [`Aurion::probe_capabilities`].
+    pub async fn probe_capabilities(&self) -> Result<Capabilities> {
+        self.inner.probe_capabilities().await
+    }
+
+    /// Check whether the planning page with the given id still exists.
+    ///
+    /// See [`Aurion::planning_exists`].
+    pub async fn planning_exists<I: Into<String>>(&self, id: I) -> Result<bool> {
+        self.inner.planning_exists(id).await
+    }
+
+    /// Get the class groups designated by class_group_id.
+    ///
+    /// See [`Aurion::get_class_groups`].
+    pub async fn get_class_groups<I: Into<String>>(
+        &self,
+        class_group_id: I,
+    ) -> Result<Vec<ClassGroup>> {
+        self.inner.get_class_groups(class_group_id).await
+    }
+
+    /// Get the class groups designated by class_group_id matching the query.
+    ///
+    /// See [`Aurion::get_class_groups_matching`].
+    pub async fn get_class_groups_matching<I: Into<String>, Q: AsRef<str>>(
+        &self,
+        class_group_id: I,
+        query: Q,
+    ) -> Result<Vec<ClassGroup>> {
+        self.inner
+            .get_class_groups_matching(class_group_id, query)
+            .await
+    }
+
+    /// Get the favorite plannings of a planning choice page.
+    ///
+    /// See [`Aurion::get_favorite_plannings`].
+    pub async fn get_favorite_plannings<I: Into<String>>(
+        &self,
+        class_group_id: I,
+    ) -> Result<Vec<ClassGroup>> {
+        self.inner.get_favorite_plannings(class_group_id).await
+    }
+
+    /// Get the schedule of a class group.
+    ///
+    /// See [`Aurion::get_group_schedule`].
+    pub async fn get_group_schedule(
+        &self,
+        group: &ClassGroup,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        self.inner.get_group_schedule(group, start, end).await
+    }
+
+    /// Get the schedules of multiple class groups.
+    ///
+    /// See [`Aurion::get_group_schedules`].
+    pub async fn get_group_schedules(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<HashMap<ClassGroup, Vec<Event>>> {
+        self.inner.get_group_schedules(groups, start, end).await
+    }
+
+    /// Get the merged schedule of multiple class groups.
+    ///
+    /// See [`Aurion::get_combined_schedule`].
+    pub async fn get_combined_schedule(
+        &self,
+        groups: &[ClassGroup],
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        self.inner.get_combined_schedule(groups, start, end).await
+    }
+
+    /// Get the schedule of a room.
+    ///
+    /// See [`Aurion::get_room_schedule`].
+    pub async fn get_room_schedule(
+        &self,
+        room_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        self.inner.get_room_schedule(room_id, start, end).await
+    }
+
+    /// Get the teachers whose planning can be fetched.
+    ///
+    /// See [`Aurion::get_teachers`].
+    pub async fn get_teachers(&self) -> Result<Vec<Teacher>> {
+        self.inner.get_teachers().await
+    }
+
+    /// Get the schedule of a teacher.
+    ///
+    /// See [`Aurion::get_teacher_schedule`].
+    pub async fn get_teacher_schedule(
+        &self,
+        teacher_id: u32,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        self.inner
+            .get_teacher_schedule(teacher_id, start, end)
+            .await
+    }
+
+    /// Get the details of an event of the last fetched schedule.
+    ///
+    /// See [`Aurion::get_event_details`].
+    pub async fn get_event_details(&self, event_id: &EventId) -> Result<EventDetails> {
+        self.inner.get_event_details(event_id).await
+    }
+
+    /// Get the administrative documents that can be downloaded.
+    ///
+    /// See [`Aurion::list_documents`].
+    pub async fn list_documents(&self) -> Result<Vec<Document>> {
+        self.inner.list_documents().await
+    }
+
+    /// Get the personal information of the logged in user.
+    ///
+    /// See [`Aurion::get_profile`].
+    pub async fn get_profile(&self) -> Result<Profile> {
+        self.inner.get_profile().await
+    }
+
+    /// Get the students of a class group, listed in the trombinoscope.
+    ///
+    /// See [`Aurion::get_roster`].
+    pub async fn get_roster(&self, group_id: u32) -> Result<Vec<Student>> {
+        self.inner.get_roster(group_id).await
+    }
+
+    /// Download the photo of a student.
+    ///
+    /// See [`Aurion::get_student_photo`].
+    pub async fn get_student_photo(&self, student: &Student) -> Result<Bytes> {
+        self.inner.get_student_photo(student).await
+    }
+
+    /// Get the exams listed in the exams page.
+    ///
+    /// See [`Aurion::get_exams`].
+    pub async fn get_exams(&self) -> Result<Vec<Exam>> {
+        self.inner.get_exams().await
+    }
+
+    /// Get the internship offers published in Aurion.
+    ///
+    /// See [`Aurion::get_internship_offers`].
+    pub async fn get_internship_offers(&self) -> Result<Vec<InternshipOffer>> {
+        self.inner.get_internship_offers().await
+    }
+
+    /// Get an internship offer with the fields of its detail page.
+    ///
+    /// See [`Aurion::get_internship_offer`].
+    pub async fn get_internship_offer(&self, offer_id: &str) -> Result<InternshipOffer> {
+        self.inner.get_internship_offer(offer_id).await
+    }
+
+    /// Get the internships of the user.
+    ///
+    /// See [`Aurion::get_internships`].
+    pub async fn get_internships(&self) -> Result<Vec<Internship>> {
+        self.inner.get_internships().await
+    }
+
+    /// Download an administrative document.
+    ///
+    /// See [`Aurion::download_document`].
+    pub async fn download_document(&self, document_id: &str) -> Result<Bytes> {
+        self.inner.download_document(document_id).await
+    }
+
+    /// Get the grade bulletins published for the user.
+    ///
+    /// See [`Aurion::list_report_cards`].
+    pub async fn list_report_cards(&self) -> Result<Vec<ReportCard>> {
+        self.inner.list_report_cards().await
+    }
+
+    /// Download the grade bulletin of the given semester.
+    ///
+    /// See [`Aurion::download_report_card`].
+    pub async fn download_report_card(&self, semester: &str) -> Result<Bytes> {
+        self.inner.download_report_card(semester).await
+    }
+
+    /// Download the school certificate of the user.
+    ///
+    /// See [`Aurion::download_school_certificate`].
+    pub async fn download_school_certificate(&self) -> Result<Bytes> {
+        self.inner.download_school_certificate().await
+    }
+
+    /// Get the plannings of the user.
+    ///
+    /// See [`Aurion::get_user_plannings`].
+    pub async fn get_user_plannings(&self) -> Result<Vec<UserPlanning>> {
+        self.inner.get_user_plannings().await
+    }
+
+    /// Select the user planning fetched by `get_user_schedule`.
+    ///
+    /// See [`Aurion::select_user_planning`].
+    pub async fn select_user_planning<S: Into<UserPlanningSelector>>(
+        &self,
+        selector: S,
+    ) -> Result<UserPlanning> {
+        self.inner.select_user_planning(selector).await
+    }
+
+    /// Get the user's schedule.
+    ///
+    /// See [`Aurion::get_user_schedule`].
+    pub async fn get_user_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Event>> {
+        self.inner.get_user_schedule(start, end).await
+    }
+
+    /// Get the user's schedule of the given day.
+    ///
+    /// See [`Aurion::get_user_schedule_day`].
+    pub async fn get_user_schedule_day(&self, date: NaiveDate) -> Result<Vec<Event>> {
+        self.inner.get_user_schedule_day(date).await
+    }
+
+    /// Get the user's schedule of today.
+    ///
+    /// See [`Aurion::get_user_schedule_today`].
+    pub async fn get_user_schedule_today(&self) -> Result<Vec<Event>> {
+        self.inner.get_user_schedule_today().await
+    }
+
+    /// Get the user's schedule of a week.
+    ///
+    /// See [`Aurion::get_user_schedule_week`].
+    pub async fn get_user_schedule_week(&self, week_offset: i64) -> Result<Vec<Event>> {
+        self.inner.get_user_schedule_week(week_offset).await
+    }
+
+    /// Get the schedule described by the given request.
+    ///
+    /// See [`Aurion::fetch_schedule`].
+    pub async fn fetch_schedule(&self, request: &ScheduleRequest) -> Result<Vec<Event>> {
+        self.inner.fetch_schedule(request).await
+    }
+
+    /// Stream the user's schedule chunk by chunk.
+    ///
+    /// See [`Aurion::stream_schedule`].
+    pub fn stream_schedule(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Stream<Item = Result<Event>> + '_ {
+        self.inner.stream_schedule(start, end)
+    }
+}
+
+impl<T: Transport> From<Aurion<T>> for ReadOnlyAurion<T> {
+    fn from(aurion: Aurion<T>) -> Self {
+        Self::new(aurion)
+    }
+}
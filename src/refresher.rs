@@ -0,0 +1,124 @@
+#![deny(missing_docs)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::{debug, warn};
+
+use crate::aurion::Aurion;
+use crate::event::Event;
+use crate::transport::Transport;
+
+/// The handle of a background task refreshing the user's schedule, see
+/// [`Aurion::spawn_refresher`].
+///
+/// The task is stopped once the handle is dropped.
+pub struct RefresherHandle {
+    task: JoinHandle<()>,
+}
+
+impl RefresherHandle {
+    /// Stop the refresher task.
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+
+    /// Check whether the refresher task is stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for RefresherHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl<T: Transport + 'static> Aurion<T> {
+    /// Spawn a task refreshing the user's schedule on the given interval,
+    /// the first refresh happening right away.
+    ///
+    /// The latest fetched schedule is published to the returned receiver,
+    /// which starts with an empty schedule. Failures to refresh the schedule
+    /// are only logged, and the receiver keeps the last fetched schedule
+    /// until the server answers again.
+    ///
+    /// The Aurion session must be logged in. The task is spawned on the
+    /// current Tokio runtime, and this must be called from within one.
+    ///
+    /// This method requires the `refresher` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aurion_rs::Aurion;
+    /// use std::time::Duration;
+    ///
+    /// # async fn run(aurion: Aurion) -> anyhow::Result<()> {
+    /// let (_handle, mut schedule) = aurion.spawn_refresher(Duration::from_secs(30 * 60));
+    /// while schedule.changed().await.is_ok() {
+    ///     println!("{} events", schedule.borrow().len());
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn spawn_refresher(
+        self,
+        period: Duration,
+    ) -> (RefresherHandle, watch::Receiver<Arc<Vec<Event>>>) {
+        let (sender, receiver) = watch::channel(Arc::new(Vec::new()));
+
+        let task = tokio::spawn(async move {
+            let mut ticks = interval(period);
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+
+                // Skip the schedule cache to fetch a fresh schedule
+                debug!("Refreshing the schedule");
+                self.force_refresh();
+                match self.get_user_schedule(None, None).await {
+                    Ok(events) => {
+                        if sender.send(Arc::new(events)).is_err() {
+                            debug!("Schedule receiver dropped, stopping the refresher");
+                            break;
+                        }
+                    }
+                    Err(e) => warn!("Failed to refresh the schedule: {}", e),
+                }
+            }
+        });
+
+        (RefresherHandle { task }, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::test_support::{aurion, event, FakeTransport};
+
+    #[tokio::test]
+    async fn publishes_the_schedule_once_aurion_answers_again() {
+        let transport = FakeTransport::new(vec![event("42", Utc::now())]);
+        transport.set_failing(true);
+
+        let (handle, mut schedule) =
+            aurion(transport.clone()).spawn_refresher(Duration::from_millis(10));
+        while transport.failures() < 1 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(schedule.borrow().is_empty());
+
+        transport.set_failing(false);
+        schedule.changed().await.unwrap();
+        assert_eq!(schedule.borrow()[0].id.as_str(), "42");
+        assert!(!handle.is_stopped());
+    }
+}
@@ -0,0 +1,3 @@
+mod student;
+
+pub use student::Student;
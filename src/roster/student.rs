@@ -0,0 +1,21 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// A student listed in the trombinoscope of a class group.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Student {
+    /// The name of the student, as written under the photo.
+    pub name: String,
+
+    /// The absolute url of the photo of the student, to be downloaded with
+    /// [`Aurion::get_student_photo`](crate::Aurion::get_student_photo).
+    pub photo_url: Option<String>,
+}
+
+impl Student {
+    /// Create a new student.
+    pub fn new(name: String, photo_url: Option<String>) -> Self {
+        Self { name, photo_url }
+    }
+}
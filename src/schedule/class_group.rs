@@ -2,19 +2,67 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::GroupId;
+
 /// A class group.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ClassGroup {
     /// The id of the class group.
     pub id: u32,
 
     /// The name of the class group.
     pub name: String,
+
+    /// The id of the menu page listing the class group.
+    pub menu_id: String,
+
+    /// The text of the columns of the class group row in the planning choice
+    /// table, such as its code and its label.
+    #[serde(default)]
+    pub columns: Vec<String>,
 }
 
 impl ClassGroup {
     /// Create a new class group.
-    pub fn new(id: u32, name: String) -> Self {
-        Self { id, name }
+    pub fn new(id: u32, name: String, menu_id: String) -> Self {
+        Self {
+            id,
+            name,
+            menu_id,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Set the text of the columns of the class group row.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = columns;
+        self
     }
-}
\ No newline at end of file
+
+    /// Check whether the name or one of the columns of the class group
+    /// contains the given query, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::schedule::ClassGroup;
+    ///
+    /// let group = ClassGroup::new(1, "CIR 1 Brest".to_string(), "1_4".to_string())
+    ///     .with_columns(vec!["CIR1_BREST".to_string(), "CIR 1 Brest".to_string()]);
+    ///
+    /// assert!(group.matches("cir 1"));
+    /// assert!(group.matches("CIR1_"));
+    /// assert!(!group.matches("CIR 2"));
+    /// ```
+    pub fn matches<Q: AsRef<str>>(&self, query: Q) -> bool {
+        let query = query.as_ref().to_lowercase();
+        std::iter::once(&self.name)
+            .chain(&self.columns)
+            .any(|text| text.to_lowercase().contains(&query))
+    }
+
+    /// Get the id of the class group.
+    pub fn group_id(&self) -> GroupId {
+        GroupId(self.id)
+    }
+}
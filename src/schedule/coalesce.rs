@@ -0,0 +1,75 @@
+#![deny(missing_docs)]
+
+use crate::event::Event;
+
+/// Merge the back-to-back sessions of a same course into longer events.
+///
+/// Practical works are often split into consecutive blocks of one hour.
+/// Events are merged when one starts exactly when the previous one ends and
+/// they share the same kind, subject, chapter, rooms, participants, group and
+/// remote flag. The merged event keeps the id and raw fields of its first
+/// session. The returned events are sorted by start date.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::coalesce;
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = |id: u32, room: &str, start: u32| Event {
+///     id: id.into(),
+///     kind: EventKind::PracticalWork,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, start, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, start + 1, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec![room.into()],
+///     is_remote: false,
+///     subject: "Electronics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let events = vec![
+///     event(1, "B101", 8),
+///     event(2, "B101", 9),
+///     event(3, "B101", 10),
+///     // Another room, so another session
+///     event(4, "B102", 11),
+/// ];
+///
+/// let events = coalesce(events);
+/// assert_eq!(events.len(), 2);
+/// assert_eq!(events[0].id.as_str(), "1");
+/// assert_eq!(events[0].end, Utc.with_ymd_and_hms(2023, 3, 6, 11, 0, 0).unwrap());
+/// ```
+pub fn coalesce(mut events: Vec<Event>) -> Vec<Event> {
+    events.sort_by_key(|event| event.start);
+
+    let mut merged: Vec<Event> = Vec::with_capacity(events.len());
+    for event in events {
+        // Events are sorted, so only the last merged event can be extended
+        match merged.last_mut() {
+            Some(last) if is_continuation(last, &event) => last.end = event.end,
+            _ => merged.push(event),
+        }
+    }
+
+    merged
+}
+
+/// Check whether an event is the next session of the previous one.
+fn is_continuation(previous: &Event, event: &Event) -> bool {
+    previous.end == event.start
+        && previous.kind == event.kind
+        && previous.subject == event.subject
+        && previous.chapter == event.chapter
+        && previous.rooms == event.rooms
+        && previous.participants == event.participants
+        && previous.group_id == event.group_id
+        && previous.is_remote == event.is_remote
+}
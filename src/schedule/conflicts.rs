@@ -0,0 +1,75 @@
+#![deny(missing_docs)]
+
+use crate::event::Event;
+
+/// Get the pairs of events overlapping each other, even partially.
+///
+/// Events ending exactly when another one starts don't overlap. Pairs of
+/// events with the same id are considered duplicates rather than conflicts
+/// and are skipped. The pairs are sorted by the start date of their first
+/// event.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::{conflicts, room_conflicts};
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = |id: u32, room: &str, start: u32, end: u32| Event {
+///     id: id.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, start, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, end, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec![room.into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let events = [
+///     event(1, "B101", 8, 10),
+///     event(2, "B102", 9, 11),
+///     event(3, "B101", 9, 10),
+///     event(4, "B101", 10, 12),
+/// ];
+///
+/// assert_eq!(conflicts(&events).len(), 4);
+/// assert_eq!(room_conflicts(&events).len(), 1);
+/// ```
+pub fn conflicts(events: &[Event]) -> Vec<(Event, Event)> {
+    let mut sorted: Vec<&Event> = events.iter().collect();
+    sorted.sort_by_key(|event| event.start);
+
+    let mut conflicts = Vec::new();
+    for (index, event) in sorted.iter().enumerate() {
+        // Events are sorted by start, so only the following events starting
+        // before this one ends can overlap it
+        for other in sorted[index + 1..]
+            .iter()
+            .take_while(|other| other.start < event.end)
+        {
+            if event.id != other.id && other.end > other.start {
+                conflicts.push(((*event).clone(), (*other).clone()));
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Get the pairs of overlapping events taking place in a same room.
+///
+/// See [`conflicts`].
+pub fn room_conflicts(events: &[Event]) -> Vec<(Event, Event)> {
+    conflicts(events)
+        .into_iter()
+        .filter(|(event, other)| event.rooms.iter().any(|room| other.rooms.contains(room)))
+        .collect()
+}
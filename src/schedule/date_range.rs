@@ -0,0 +1,73 @@
+#![deny(missing_docs)]
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A range of time, from `start` included to `end` excluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DateRange {
+    /// The start of the range.
+    pub start: DateTime<Utc>,
+
+    /// The end of the range.
+    pub end: DateTime<Utc>,
+}
+
+impl DateRange {
+    /// Create a new range.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start, end }
+    }
+
+    /// Get the duration of the range.
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Check if the given instant is in the range.
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    /// Check if the range overlaps another one.
+    pub fn overlaps(&self, other: &DateRange) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Split the range into consecutive ranges of at most the given
+    /// duration, the last one being shorter if needed.
+    ///
+    /// A range that is empty or a duration that isn't positive gives the
+    /// range itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::schedule::DateRange;
+    /// use chrono::{Duration, TimeZone, Utc};
+    ///
+    /// let range = DateRange::new(
+    ///     Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2023, 3, 11, 0, 0, 0).unwrap(),
+    /// );
+    /// let chunks = range.split(Duration::days(4));
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(chunks[0].end, chunks[1].start);
+    /// assert_eq!(chunks[2].duration(), Duration::days(2));
+    /// ```
+    pub fn split(&self, size: Duration) -> Vec<DateRange> {
+        if self.start >= self.end || size <= Duration::zero() {
+            return vec![*self];
+        }
+
+        let mut chunks = Vec::new();
+        let mut start = self.start;
+        while start < self.end {
+            let end = std::cmp::min(start + size, self.end);
+            chunks.push(DateRange::new(start, end));
+            start = end;
+        }
+
+        chunks
+    }
+}
@@ -0,0 +1,76 @@
+#![deny(missing_docs)]
+
+use crate::event::Event;
+
+/// Remove the duplicated events, such as the ones appearing in both the user
+/// planning and a group planning.
+///
+/// Two events are duplicates when they share the same id and dates. As ids
+/// may differ between plannings, events with different ids are also
+/// considered duplicates when they share the same dates, kind, subject and
+/// rooms, the subject being compared case-insensitively. The first occurrence
+/// of each event is kept, and the order of the events is preserved.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::dedup;
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = |id: u32, subject: &str, start: u32| Event {
+///     id: id.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, start, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, start + 2, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["B101".into()],
+///     is_remote: false,
+///     subject: subject.to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let events = vec![
+///     event(1, "Mathematics", 8),
+///     event(1, "Mathematics", 8),
+///     // Same event listed with another id in a group planning
+///     event(2, "MATHEMATICS", 8),
+///     event(3, "Mathematics", 10),
+/// ];
+///
+/// let events = dedup(events);
+/// assert_eq!(events.len(), 2);
+/// assert_eq!(events[0].id.as_str(), "1");
+/// assert_eq!(events[1].id.as_str(), "3");
+/// ```
+pub fn dedup(events: Vec<Event>) -> Vec<Event> {
+    let mut unique: Vec<Event> = Vec::with_capacity(events.len());
+    for event in events {
+        if !unique.iter().any(|other| is_duplicate(&event, other)) {
+            unique.push(event);
+        }
+    }
+
+    unique
+}
+
+/// Check whether two events are the same event, see [`dedup`].
+pub(crate) fn is_duplicate(event: &Event, other: &Event) -> bool {
+    if event.start != other.start || event.end != other.end {
+        return false;
+    }
+
+    if event.id == other.id {
+        return true;
+    }
+
+    event.kind == other.kind
+        && event.subject.to_lowercase() == other.subject.to_lowercase()
+        && event.rooms.len() == other.rooms.len()
+        && event.rooms.iter().all(|room| other.rooms.contains(room))
+}
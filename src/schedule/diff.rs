@@ -0,0 +1,117 @@
+#![deny(missing_docs)]
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::event::Event;
+
+/// A change of an event between two versions of a schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventChange {
+    /// The event in the old schedule.
+    pub old: Event,
+
+    /// The event in the new schedule.
+    pub new: Event,
+}
+
+/// The differences between two versions of a schedule.
+///
+/// Events are matched by their id. An event whose time and rooms both
+/// changed appears in both `moved` and `room_changed`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScheduleDiff {
+    /// The events only present in the new schedule.
+    pub added: Vec<Event>,
+
+    /// The events only present in the old schedule.
+    pub removed: Vec<Event>,
+
+    /// The events whose start or end date changed.
+    pub moved: Vec<EventChange>,
+
+    /// The events whose rooms changed.
+    pub room_changed: Vec<EventChange>,
+}
+
+impl ScheduleDiff {
+    /// Check if the two schedules are identical regarding the tracked
+    /// changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.moved.is_empty()
+            && self.room_changed.is_empty()
+    }
+}
+
+/// Compute the differences between an old and a new version of a schedule.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::diff;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let old = Event {
+///     id: 42.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["B101".into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let mut new = old.clone();
+/// new.start = new.start + Duration::hours(1);
+/// new.end = new.end + Duration::hours(1);
+///
+/// let diff = diff(&[old], &[new]);
+/// assert_eq!(diff.moved.len(), 1);
+/// assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.room_changed.is_empty());
+/// ```
+pub fn diff(old: &[Event], new: &[Event]) -> ScheduleDiff {
+    let old_events: HashMap<_, _> = old.iter().map(|event| (&event.id, event)).collect();
+    let new_ids: HashSet<_> = new.iter().map(|event| &event.id).collect();
+
+    let mut diff = ScheduleDiff::default();
+
+    for event in new {
+        let old_event = match old_events.get(&event.id) {
+            Some(old_event) => *old_event,
+            None => {
+                diff.added.push(event.clone());
+                continue;
+            }
+        };
+
+        let change = || EventChange {
+            old: old_event.clone(),
+            new: event.clone(),
+        };
+        if old_event.start != event.start || old_event.end != event.end {
+            diff.moved.push(change());
+        }
+        if old_event.rooms != event.rooms {
+            diff.room_changed.push(change());
+        }
+    }
+
+    diff.removed = old
+        .iter()
+        .filter(|event| !new_ids.contains(&event.id))
+        .cloned()
+        .collect();
+
+    diff
+}
@@ -0,0 +1,146 @@
+#![deny(missing_docs)]
+
+use chrono::{DateTime, Utc};
+
+use crate::event::{Event, EventKind};
+
+/// A query to filter events.
+///
+/// Every criterion must match for an event to be selected, except for the
+/// kinds: an event matches if it is of any of the queried kinds. Text
+/// criteria are case-insensitive and match substrings.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::EventQuery;
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Exam,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["NANTES L012".into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec!["DOE John".into()],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let events = vec![event];
+///
+/// let query = EventQuery::new()
+///     .kind(EventKind::Exam)
+///     .subject_contains("math")
+///     .room("L012")
+///     .participant("Doe");
+/// assert_eq!(query.filter(&events).len(), 1);
+///
+/// let query = EventQuery::new().kind(EventKind::Course);
+/// assert!(query.filter(&events).is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EventQuery {
+    kinds: Vec<EventKind>,
+    subjects: Vec<String>,
+    rooms: Vec<String>,
+    participants: Vec<String>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+}
+
+impl EventQuery {
+    /// Create a new query matching every event.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select the events of the given kind.
+    /// Calling this multiple times selects the events of any of the kinds.
+    pub fn kind(mut self, kind: EventKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Select the events whose subject contains the given text.
+    pub fn subject_contains<S: AsRef<str>>(mut self, subject: S) -> Self {
+        self.subjects.push(subject.as_ref().to_lowercase());
+        self
+    }
+
+    /// Select the events taking place in a room containing the given text.
+    pub fn room<S: AsRef<str>>(mut self, room: S) -> Self {
+        self.rooms.push(room.as_ref().to_lowercase());
+        self
+    }
+
+    /// Select the events with a participant containing the given text.
+    pub fn participant<S: AsRef<str>>(mut self, participant: S) -> Self {
+        self.participants.push(participant.as_ref().to_lowercase());
+        self
+    }
+
+    /// Select the events overlapping the range from `start` to `end`.
+    pub fn between(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    /// Check if an event matches the query.
+    pub fn matches(&self, event: &Event) -> bool {
+        if !self.kinds.is_empty() && !self.kinds.contains(&event.kind) {
+            return false;
+        }
+
+        let subject = event.subject.to_lowercase();
+        if !self.subjects.iter().all(|s| subject.contains(s)) {
+            return false;
+        }
+
+        fn any_contains<V: AsRef<str>>(values: &[V], text: &str) -> bool {
+            values
+                .iter()
+                .any(|value| value.as_ref().to_lowercase().contains(text))
+        }
+        if !self.rooms.iter().all(|r| any_contains(&event.rooms, r)) {
+            return false;
+        }
+        if !self
+            .participants
+            .iter()
+            .all(|p| any_contains(&event.participants, p))
+        {
+            return false;
+        }
+
+        if self.start.is_some_and(|start| event.end <= start) {
+            return false;
+        }
+        if self.end.is_some_and(|end| event.start >= end) {
+            return false;
+        }
+
+        true
+    }
+
+    /// Get the events matching the query.
+    pub fn filter<'a>(&self, events: &'a [Event]) -> Vec<&'a Event> {
+        events.iter().filter(|event| self.matches(event)).collect()
+    }
+
+    /// Keep only the events matching the query.
+    pub fn apply(&self, events: Vec<Event>) -> Vec<Event> {
+        events
+            .into_iter()
+            .filter(|event| self.matches(event))
+            .collect()
+    }
+}
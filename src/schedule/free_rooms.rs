@@ -0,0 +1,96 @@
+#![deny(missing_docs)]
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+
+use super::DateRange;
+use crate::event::Event;
+
+/// Get the rooms that are free at the given instant.
+///
+/// The known rooms are the ones appearing in the given events, so the
+/// events should cover all the rooms of interest, for example the group
+/// schedules of a whole campus. The rooms are sorted by name.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::{free_rooms, free_slots_for_room, DateRange};
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = |room: &str, start: u32, end: u32| Event {
+///     id: start.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, start, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, end, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec![room.into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let events = [event("B101", 8, 10), event("B102", 10, 12)];
+///
+/// let at = Utc.with_ymd_and_hms(2023, 3, 6, 9, 0, 0).unwrap();
+/// assert_eq!(free_rooms(&events, at), ["B102"]);
+///
+/// let day = DateRange::new(
+///     Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2023, 3, 6, 18, 0, 0).unwrap(),
+/// );
+/// let slots = free_slots_for_room(&events, "B101", day);
+/// assert_eq!(slots, [DateRange::new(events[0].end, day.end)]);
+/// ```
+pub fn free_rooms(events: &[Event], at: DateTime<Utc>) -> Vec<String> {
+    let mut rooms: BTreeSet<&str> = events
+        .iter()
+        .flat_map(|event| &event.rooms)
+        .map(|room| room.raw.as_str())
+        .collect();
+
+    for event in events {
+        if event.start <= at && at < event.end {
+            for room in &event.rooms {
+                rooms.remove(room.raw.as_str());
+            }
+        }
+    }
+
+    rooms.into_iter().map(String::from).collect()
+}
+
+/// Get the slots of the given range during which the room is free, sorted by
+/// start date.
+pub fn free_slots_for_room(events: &[Event], room: &str, range: DateRange) -> Vec<DateRange> {
+    // Get the busy slots of the room within the range
+    let mut busy: Vec<DateRange> = events
+        .iter()
+        .filter(|event| event.rooms.iter().any(|r| r == room))
+        .map(|event| DateRange::new(event.start, event.end))
+        .filter(|slot| slot.overlaps(&range))
+        .collect();
+    busy.sort_by_key(|slot| slot.start);
+
+    // Collect the gaps between the busy slots
+    let mut free = Vec::new();
+    let mut cursor = range.start;
+    for slot in busy {
+        if slot.start > cursor {
+            free.push(DateRange::new(cursor, slot.start));
+        }
+        cursor = cursor.max(slot.end);
+    }
+    if cursor < range.end {
+        free.push(DateRange::new(cursor, range.end));
+    }
+
+    free
+}
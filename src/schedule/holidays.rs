@@ -0,0 +1,77 @@
+#![deny(missing_docs)]
+
+use chrono::Duration;
+
+use super::DateRange;
+use crate::event::{Event, EventKind};
+
+/// The longest gap between two leave events of the same holiday period,
+/// long enough to span a weekend.
+const MAX_GAP_DAYS: i64 = 3;
+
+/// Merge the leave events into holiday periods, sorted by start date.
+///
+/// Two leave events belong to the same period when they overlap or are
+/// separated by less than three days, such as a weekend, and no other event
+/// takes place in between. The events don't need to be sorted.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::{holidays, DateRange};
+/// use chrono::{TimeZone, Utc};
+///
+/// let leave = |day: u32| Event {
+///     id: day.into(),
+///     kind: EventKind::Leave,
+///     start: Utc.with_ymd_and_hms(2023, 4, day, 0, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 4, day, 23, 59, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec![],
+///     is_remote: false,
+///     subject: "Vacances".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// // Two weeks of leave, from Monday to Friday
+/// let events = [10, 11, 12, 13, 14, 17, 18, 19, 20, 21].map(leave);
+///
+/// assert_eq!(
+///     holidays(&events),
+///     [DateRange::new(events[0].start, events[9].end)]
+/// );
+/// ```
+pub fn holidays(events: &[Event]) -> Vec<DateRange> {
+    let mut leaves = events
+        .iter()
+        .filter(|event| event.kind == EventKind::Leave)
+        .map(|event| DateRange::new(event.start, event.end))
+        .collect::<Vec<DateRange>>();
+    leaves.sort_by_key(|leave| leave.start);
+
+    let mut periods: Vec<DateRange> = Vec::new();
+    for leave in leaves {
+        if let Some(period) = periods.last_mut() {
+            let gap = DateRange::new(period.end, leave.start);
+            let contiguous = leave.start <= period.end
+                || (gap.duration() < Duration::days(MAX_GAP_DAYS)
+                    && !events.iter().any(|event| {
+                        event.kind != EventKind::Leave
+                            && DateRange::new(event.start, event.end).overlaps(&gap)
+                    }));
+            if contiguous {
+                period.end = period.end.max(leave.end);
+                continue;
+            }
+        }
+        periods.push(leave);
+    }
+
+    periods
+}
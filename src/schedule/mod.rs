@@ -1,3 +1,43 @@
 mod class_group;
+mod coalesce;
+mod conflicts;
+mod date_range;
+mod dedup;
+mod diff;
+mod event_query;
+mod free_rooms;
+mod group_id;
+mod holidays;
+mod schedule;
+mod schedule_range;
+mod schedule_request;
+mod teacher;
+mod time_grid;
+mod upcoming;
+mod user_planning;
 
-pub use class_group::ClassGroup;
\ No newline at end of file
+pub use class_group::ClassGroup;
+pub use coalesce::coalesce;
+pub use conflicts::conflicts;
+pub use conflicts::room_conflicts;
+pub use date_range::DateRange;
+pub use dedup::dedup;
+pub use diff::diff;
+pub use diff::EventChange;
+pub use diff::ScheduleDiff;
+pub use event_query::EventQuery;
+pub use free_rooms::free_rooms;
+pub use free_rooms::free_slots_for_room;
+pub use group_id::GroupId;
+pub use holidays::holidays;
+pub use schedule::Schedule;
+pub use schedule_range::ScheduleRange;
+pub use schedule_request::ScheduleRequest;
+pub use teacher::Teacher;
+pub use time_grid::TimeGrid;
+pub use upcoming::current_event;
+pub use upcoming::next_event;
+pub use user_planning::UserPlanning;
+pub use user_planning::UserPlanningSelector;
+
+pub(crate) use dedup::is_duplicate;
@@ -0,0 +1,145 @@
+#![deny(missing_docs)]
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+use chrono_tz::Tz;
+
+use crate::event::Event;
+
+/// A list of events sorted by start date and indexed by day, to render a
+/// schedule day by day or week by week.
+///
+/// Days and weeks are computed in the given timezone, usually the school
+/// timezone, and weeks start on Monday. An event belongs to the day it
+/// starts.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::Schedule;
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let event = |day: u32, hour: u32| Event {
+///     id: (day * 100 + hour).into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, day, hour, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, day, hour + 2, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec![],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// // Monday 6 and Tuesday 7, then Monday 13
+/// let events = vec![event(7, 8), event(6, 10), event(6, 8), event(13, 8)];
+/// let schedule = Schedule::new(events, chrono_tz::Europe::Paris);
+///
+/// let monday = NaiveDate::from_ymd_opt(2023, 3, 6).unwrap();
+/// assert_eq!(schedule.get(monday).len(), 2);
+/// assert!(schedule.get(monday)[0].start < schedule.get(monday)[1].start);
+/// assert_eq!(schedule.days().count(), 3);
+///
+/// let weeks = schedule.weeks().collect::<Vec<_>>();
+/// assert_eq!(weeks.len(), 2);
+/// assert_eq!(weeks[0].0, monday);
+/// assert_eq!(weeks[0].1.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    events: Vec<Event>,
+    timezone: Tz,
+    days: BTreeMap<NaiveDate, Range<usize>>,
+    weeks: BTreeMap<NaiveDate, Range<usize>>,
+}
+
+impl Schedule {
+    /// Create a schedule from the given events, sorted by start date.
+    pub fn new(mut events: Vec<Event>, timezone: Tz) -> Self {
+        events.sort_by_key(|event| event.start);
+
+        // As the events are sorted, the events of a day or a week are
+        // contiguous
+        let mut days: BTreeMap<NaiveDate, Range<usize>> = BTreeMap::new();
+        let mut weeks: BTreeMap<NaiveDate, Range<usize>> = BTreeMap::new();
+        for (index, event) in events.iter().enumerate() {
+            let day = event.start_in(timezone).date_naive();
+            let monday = day.week(Weekday::Mon).first_day();
+            days.entry(day).or_insert(index..index).end = index + 1;
+            weeks.entry(monday).or_insert(index..index).end = index + 1;
+        }
+
+        Self {
+            events,
+            timezone,
+            days,
+            weeks,
+        }
+    }
+
+    /// Get the timezone in which the days are computed.
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Get all the events, sorted by start date.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Get the events, sorted by start date.
+    pub fn into_events(self) -> Vec<Event> {
+        self.events
+    }
+
+    /// Get the number of events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check whether the schedule has no event.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Get the events starting on the given day, sorted by start date.
+    pub fn get(&self, date: NaiveDate) -> &[Event] {
+        match self.days.get(&date) {
+            Some(range) => &self.events[range.clone()],
+            None => &[],
+        }
+    }
+
+    /// Iterate over the days with events, in chronological order, along with
+    /// their events.
+    pub fn days(&self) -> impl Iterator<Item = (NaiveDate, &[Event])> {
+        self.days
+            .iter()
+            .map(|(day, range)| (*day, &self.events[range.clone()]))
+    }
+
+    /// Iterate over the weeks with events, in chronological order, along
+    /// with their events. Weeks are identified by their Monday.
+    pub fn weeks(&self) -> impl Iterator<Item = (NaiveDate, &[Event])> {
+        self.weeks
+            .iter()
+            .map(|(monday, range)| (*monday, &self.events[range.clone()]))
+    }
+}
+
+impl IntoIterator for Schedule {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
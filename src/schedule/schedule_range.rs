@@ -0,0 +1,102 @@
+#![deny(missing_docs)]
+
+use std::ops::RangeInclusive;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use super::DateRange;
+use crate::utils::day_range;
+
+/// The range of a schedule fetch, either as instants or as days of the
+/// school timezone.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::schedule::ScheduleRange;
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let monday = NaiveDate::from_ymd_opt(2023, 3, 6).unwrap();
+/// let friday = NaiveDate::from_ymd_opt(2023, 3, 10).unwrap();
+///
+/// // Days are converted with the school timezone, the last one included
+/// let range = ScheduleRange::from(monday..=friday);
+/// let (start, end) = range.resolve(chrono_tz::Europe::Paris);
+/// assert_eq!(start, Some(Utc.with_ymd_and_hms(2023, 3, 5, 23, 0, 0).unwrap()));
+/// assert_eq!(end, Some(Utc.with_ymd_and_hms(2023, 3, 10, 23, 0, 0).unwrap()));
+///
+/// assert_eq!(ScheduleRange::from((monday, friday)), range);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleRange {
+    /// A range of instants, from `start` included to `end` excluded. A
+    /// missing bound is the one of the current school year.
+    Instants {
+        /// The start of the range.
+        start: Option<DateTime<Utc>>,
+
+        /// The end of the range.
+        end: Option<DateTime<Utc>>,
+    },
+
+    /// A range of days of the school timezone, both included.
+    Dates {
+        /// The first day of the range.
+        start: NaiveDate,
+
+        /// The last day of the range.
+        end: NaiveDate,
+    },
+}
+
+impl ScheduleRange {
+    /// Get the instants of the range, converting the days with the given
+    /// school timezone.
+    pub fn resolve(&self, timezone: Tz) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        match *self {
+            ScheduleRange::Instants { start, end } => (start, end),
+            ScheduleRange::Dates { start, end } => (
+                Some(day_range(start, timezone).0),
+                Some(day_range(end, timezone).1),
+            ),
+        }
+    }
+}
+
+impl Default for ScheduleRange {
+    fn default() -> Self {
+        ScheduleRange::Instants {
+            start: None,
+            end: None,
+        }
+    }
+}
+
+impl From<(NaiveDate, NaiveDate)> for ScheduleRange {
+    fn from((start, end): (NaiveDate, NaiveDate)) -> Self {
+        ScheduleRange::Dates { start, end }
+    }
+}
+
+impl From<RangeInclusive<NaiveDate>> for ScheduleRange {
+    fn from(range: RangeInclusive<NaiveDate>) -> Self {
+        let (start, end) = range.into_inner();
+        ScheduleRange::Dates { start, end }
+    }
+}
+
+impl From<(DateTime<Utc>, DateTime<Utc>)> for ScheduleRange {
+    fn from((start, end): (DateTime<Utc>, DateTime<Utc>)) -> Self {
+        ScheduleRange::Instants {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+}
+
+impl From<DateRange> for ScheduleRange {
+    fn from(range: DateRange) -> Self {
+        (range.start, range.end).into()
+    }
+}
@@ -0,0 +1,139 @@
+#![deny(missing_docs)]
+
+use chrono::{DateTime, Utc};
+
+use super::{ClassGroup, ScheduleRange};
+use crate::event::{Event, EventKind};
+
+/// The options of a schedule fetch, see [`Aurion::fetch_schedule`](crate::Aurion::fetch_schedule).
+///
+/// The request fetches the user's schedule unless a group is set, over the
+/// current school year unless dates are set.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::EventKind;
+/// use aurion_rs::schedule::ScheduleRequest;
+/// use aurion_rs::schedule::ScheduleRange;
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let request = ScheduleRequest::new()
+///     .start(Utc.with_ymd_and_hms(2023, 3, 6, 0, 0, 0).unwrap())
+///     .end(Utc.with_ymd_and_hms(2023, 3, 13, 0, 0, 0).unwrap())
+///     .kinds([EventKind::Exam])
+///     .chunked(false);
+/// assert!(request.group.is_none());
+/// assert_eq!(request.kinds, Some(vec![EventKind::Exam]));
+///
+/// // Ranges of days are converted with the school timezone when fetched
+/// let monday = NaiveDate::from_ymd_opt(2023, 3, 6).unwrap();
+/// let friday = NaiveDate::from_ymd_opt(2023, 3, 10).unwrap();
+/// let request = ScheduleRequest::new().range(monday..=friday);
+/// assert_eq!(request.range, ScheduleRange::Dates { start: monday, end: friday });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ScheduleRequest {
+    /// The fetched range, the current school year by default.
+    pub range: ScheduleRange,
+
+    /// The class group whose schedule is fetched, the user's schedule if not
+    /// set.
+    pub group: Option<ClassGroup>,
+
+    /// The kinds of the events to keep, all of them if not set.
+    pub kinds: Option<Vec<EventKind>>,
+
+    /// Whether long ranges are fetched in chunks, see
+    /// [`AurionBuilder::schedule_chunk_size`](crate::AurionBuilder::schedule_chunk_size).
+    pub chunked: bool,
+}
+
+impl ScheduleRequest {
+    /// Create a request of the user's whole schedule of the school year.
+    pub fn new() -> Self {
+        Self {
+            range: ScheduleRange::default(),
+            group: None,
+            kinds: None,
+            chunked: true,
+        }
+    }
+
+    /// Set the fetched range, either as instants or as days of the school
+    /// timezone, see [`ScheduleRange`].
+    pub fn range<R: Into<ScheduleRange>>(mut self, range: R) -> Self {
+        self.range = range.into();
+        self
+    }
+
+    /// Set the start of the fetched range.
+    ///
+    /// A range of days previously set is replaced.
+    pub fn start(mut self, start: DateTime<Utc>) -> Self {
+        self.range = match self.range {
+            ScheduleRange::Instants { end, .. } => ScheduleRange::Instants {
+                start: Some(start),
+                end,
+            },
+            ScheduleRange::Dates { .. } => ScheduleRange::Instants {
+                start: Some(start),
+                end: None,
+            },
+        };
+        self
+    }
+
+    /// Set the end of the fetched range.
+    ///
+    /// A range of days previously set is replaced.
+    pub fn end(mut self, end: DateTime<Utc>) -> Self {
+        self.range = match self.range {
+            ScheduleRange::Instants { start, .. } => ScheduleRange::Instants {
+                start,
+                end: Some(end),
+            },
+            ScheduleRange::Dates { .. } => ScheduleRange::Instants {
+                start: None,
+                end: Some(end),
+            },
+        };
+        self
+    }
+
+    /// Fetch the schedule of the given class group instead of the user's one.
+    pub fn group(mut self, group: ClassGroup) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Keep only the events of the given kinds.
+    pub fn kinds<I: IntoIterator<Item = EventKind>>(mut self, kinds: I) -> Self {
+        self.kinds = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// Set whether long ranges are fetched in chunks, which is the default.
+    pub fn chunked(mut self, chunked: bool) -> Self {
+        self.chunked = chunked;
+        self
+    }
+
+    /// Keep only the events matching the requested kinds.
+    pub(crate) fn filter(&self, events: Vec<Event>) -> Vec<Event> {
+        match &self.kinds {
+            Some(kinds) => events
+                .into_iter()
+                .filter(|event| kinds.contains(&event.kind))
+                .collect(),
+            None => events,
+        }
+    }
+}
+
+impl Default for ScheduleRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
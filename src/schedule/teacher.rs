@@ -0,0 +1,20 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// A teacher whose planning can be fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Teacher {
+    /// The id of the teacher in the planning choice table.
+    pub id: u32,
+
+    /// The name of the teacher.
+    pub name: String,
+}
+
+impl Teacher {
+    /// Create a new teacher.
+    pub fn new(id: u32, name: String) -> Self {
+        Self { id, name }
+    }
+}
@@ -0,0 +1,106 @@
+#![deny(missing_docs)]
+
+use std::ops::Range;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::DateRange;
+use crate::event::Event;
+
+/// A grid of fixed-size time slots starting at an origin, used to lay out
+/// events on a calendar grid or heatmap.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::TimeGrid;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 10, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 9, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["B101".into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+///
+/// // A grid of 30 minutes slots starting at 08:00
+/// let grid = TimeGrid::new(
+///     Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     Duration::minutes(30),
+/// );
+/// assert_eq!(grid.snap(&event), 0..2);
+///
+/// let events = [event];
+/// let slots = grid.layout(&events, 20);
+/// assert_eq!(slots[1].len(), 1);
+/// assert!(slots[2].is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeGrid {
+    origin: DateTime<Utc>,
+    slot: Duration,
+}
+
+impl TimeGrid {
+    /// Create a new grid of slots of the given duration starting at `origin`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot duration is not positive.
+    pub fn new(origin: DateTime<Utc>, slot: Duration) -> Self {
+        assert!(slot > Duration::zero(), "slot duration must be positive");
+        Self { origin, slot }
+    }
+
+    /// Get the index of the slot containing the given instant.
+    /// Instants before the origin have negative indexes.
+    pub fn slot_index(&self, instant: DateTime<Utc>) -> i64 {
+        let elapsed = (instant - self.origin).num_milliseconds();
+        elapsed.div_euclid(self.slot.num_milliseconds())
+    }
+
+    /// Get the time range of the slot at the given index.
+    pub fn slot_range(&self, index: i64) -> DateRange {
+        let start = self.origin + Duration::milliseconds(self.slot.num_milliseconds() * index);
+        DateRange::new(start, start + self.slot)
+    }
+
+    /// Snap an event to the grid, returning the range of indexes of the
+    /// slots it covers, even partially. Slots before the origin are clamped to
+    /// the first one.
+    pub fn snap(&self, event: &Event) -> Range<usize> {
+        let start = self.slot_index(event.start).max(0) as usize;
+        // The end is excluded, so an event ending on a slot boundary doesn't
+        // cover the next slot
+        let end = match event.end > event.start {
+            true => self.slot_index(event.end - Duration::milliseconds(1)) + 1,
+            false => self.slot_index(event.end),
+        };
+        start..(end.max(0) as usize).max(start)
+    }
+
+    /// Lay out the events on the first `slots` slots of the grid, returning
+    /// for each slot the events covering it.
+    pub fn layout<'a>(&self, events: &'a [Event], slots: usize) -> Vec<Vec<&'a Event>> {
+        let mut grid = vec![Vec::new(); slots];
+        for event in events {
+            let range = self.snap(event);
+            for slot in grid.iter_mut().take(range.end).skip(range.start) {
+                slot.push(event);
+            }
+        }
+        grid
+    }
+}
@@ -0,0 +1,57 @@
+#![deny(missing_docs)]
+
+use chrono::{DateTime, Utc};
+
+use crate::event::Event;
+
+/// Get the event taking place at the given instant.
+///
+/// If multiple events overlap at this instant, the one which started first
+/// is returned. The events don't need to be sorted.
+pub fn current_event(events: &[Event], now: DateTime<Utc>) -> Option<&Event> {
+    events
+        .iter()
+        .filter(|event| event.start <= now && now < event.end)
+        .min_by_key(|event| event.start)
+}
+
+/// Get the first event starting after the given instant.
+///
+/// The events don't need to be sorted.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::schedule::{current_event, next_event};
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["B101".into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+/// let events = [event];
+///
+/// let now = Utc.with_ymd_and_hms(2023, 3, 6, 7, 30, 0).unwrap();
+/// assert!(current_event(&events, now).is_none());
+/// assert_eq!(next_event(&events, now).unwrap().rooms, ["B101"]);
+/// ```
+pub fn next_event(events: &[Event], now: DateTime<Utc>) -> Option<&Event> {
+    events
+        .iter()
+        .filter(|event| event.start > now)
+        .min_by_key(|event| event.start)
+}
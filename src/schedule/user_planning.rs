@@ -0,0 +1,87 @@
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// A planning of the user, listed in the schooling menu.
+///
+/// Most accounts have a single one, but some accounts, such as double degree
+/// ones, have several.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserPlanning {
+    /// The menu id of the planning node.
+    pub menu_id: String,
+
+    /// The id of the page opened by the planning node.
+    pub id: String,
+
+    /// The label of the planning node.
+    pub name: String,
+}
+
+/// Designates one of the user plannings listed by
+/// [`Aurion::get_user_plannings`](crate::Aurion::get_user_plannings).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserPlanningSelector {
+    /// The planning at the given index.
+    Index(usize),
+
+    /// The first planning whose label contains the given name, ignoring case.
+    Name(String),
+}
+
+impl UserPlanningSelector {
+    /// Find the designated planning among the given plannings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::schedule::{UserPlanning, UserPlanningSelector};
+    ///
+    /// let plannings = [
+    ///     UserPlanning {
+    ///         menu_id: "item_1".to_string(),
+    ///         id: "1_3".to_string(),
+    ///         name: "Mon".to_string(),
+    ///     },
+    ///     UserPlanning {
+    ///         menu_id: "item_2".to_string(),
+    ///         id: "1_4".to_string(),
+    ///         name: "Mon - ENSTA".to_string(),
+    ///     },
+    /// ];
+    ///
+    /// let selector = UserPlanningSelector::from("ensta");
+    /// assert_eq!(selector.find(&plannings).unwrap().id, "1_4");
+    /// assert_eq!(UserPlanningSelector::from(0).find(&plannings).unwrap().id, "1_3");
+    /// assert!(UserPlanningSelector::from(2).find(&plannings).is_none());
+    /// ```
+    pub fn find<'a>(&self, plannings: &'a [UserPlanning]) -> Option<&'a UserPlanning> {
+        match self {
+            UserPlanningSelector::Index(index) => plannings.get(*index),
+            UserPlanningSelector::Name(name) => {
+                let name = name.to_lowercase();
+                plannings
+                    .iter()
+                    .find(|planning| planning.name.to_lowercase().contains(&name))
+            }
+        }
+    }
+}
+
+impl From<usize> for UserPlanningSelector {
+    fn from(index: usize) -> Self {
+        UserPlanningSelector::Index(index)
+    }
+}
+
+impl From<&str> for UserPlanningSelector {
+    fn from(name: &str) -> Self {
+        UserPlanningSelector::Name(name.to_string())
+    }
+}
+
+impl From<String> for UserPlanningSelector {
+    fn from(name: String) -> Self {
+        UserPlanningSelector::Name(name)
+    }
+}
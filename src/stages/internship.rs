@@ -0,0 +1,30 @@
+#![deny(missing_docs)]
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// An internship of the user, listed in the internship record.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Internship {
+    /// The id of the internship in the internship record table.
+    pub id: String,
+
+    /// The subject of the internship.
+    pub subject: Option<String>,
+
+    /// The company hosting the internship.
+    pub company: Option<String>,
+
+    /// The tutor following the internship, in the school or in the company.
+    pub tutor: Option<String>,
+
+    /// The first day of the internship.
+    pub start: Option<NaiveDate>,
+
+    /// The last day of the internship.
+    pub end: Option<NaiveDate>,
+
+    /// The status of the internship, as written by Aurion, for example
+    /// "Validé".
+    pub status: Option<String>,
+}
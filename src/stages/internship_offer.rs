@@ -0,0 +1,36 @@
+#![deny(missing_docs)]
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// An internship offer published in Aurion.
+///
+/// The description and the contact of an offer are only shown in its detail
+/// page, so they are only set by
+/// [`Aurion::get_internship_offer`](crate::Aurion::get_internship_offer).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InternshipOffer {
+    /// The id of the offer in the offers table.
+    pub id: String,
+
+    /// The title of the offer.
+    pub title: String,
+
+    /// The company offering the internship.
+    pub company: Option<String>,
+
+    /// The place where the internship takes place.
+    pub location: Option<String>,
+
+    /// The first day of the internship.
+    pub start: Option<NaiveDate>,
+
+    /// The last day of the internship.
+    pub end: Option<NaiveDate>,
+
+    /// The full description of the offer.
+    pub description: Option<String>,
+
+    /// The person to contact to apply to the offer.
+    pub contact: Option<String>,
+}
@@ -0,0 +1,5 @@
+mod internship;
+mod internship_offer;
+
+pub use internship::Internship;
+pub use internship_offer::InternshipOffer;
@@ -0,0 +1,239 @@
+#![deny(missing_docs)]
+
+//! Push events to a CalDAV calendar, such as a Nextcloud or Fastmail one.
+//!
+//! Every event is stored as its own calendar object resource, named after
+//! its [`event_uid`]. Pushing the events again after a schedule update
+//! replaces the stored events instead of duplicating them, as their UID and
+//! so their resource url don't change across runs.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use aurion_rs::Aurion;
+//! use aurion_rs::sync::caldav::CalDavClient;
+//!
+//! # async fn run(mut aurion: Aurion) -> anyhow::Result<()> {
+//! let events = aurion.get_user_schedule(None, None).await?;
+//!
+//! let client = CalDavClient::new(
+//!     "https://cloud.example.com/remote.php/dav/calendars/john/aurion/",
+//!     "john",
+//!     "app-password",
+//! )?;
+//! let report = client.push(&events, "user:1_3").await?;
+//! println!("{} created, {} updated", report.created, report.updated);
+//! #     Ok(())
+//! # }
+//! ```
+
+use anyhow::{Error, Result};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Client, ClientBuilder, Method, StatusCode, Url};
+use tracing::{debug, error};
+
+use crate::event::Event;
+use crate::export::ics;
+use crate::format::Translations;
+use crate::transport::ClientOptions;
+use crate::uid::event_uid;
+
+/// The outcome of pushing events to a CalDAV calendar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// The number of events that were not in the calendar yet.
+    pub created: usize,
+
+    /// The number of events that replaced a previously pushed version.
+    pub updated: usize,
+}
+
+/// A client pushing events to a CalDAV calendar collection.
+pub struct CalDavClient {
+    calendar_url: Url,
+    username: String,
+    password: String,
+    client: Client,
+    translations: Translations,
+}
+
+impl CalDavClient {
+    /// Create a new client for the calendar collection at the given url,
+    /// authenticated with HTTP basic authentication.
+    pub fn new<U: AsRef<str>, N: Into<String>, P: Into<String>>(
+        calendar_url: U,
+        username: N,
+        password: P,
+    ) -> Result<Self> {
+        Self::with_options(calendar_url, username, password, &ClientOptions::default())
+    }
+
+    /// Create a new client for the calendar collection at the given url,
+    /// whose HTTP client is configured with the given options, for example
+    /// the proxy and User-Agent also used to reach Aurion.
+    pub fn with_options<U: AsRef<str>, N: Into<String>, P: Into<String>>(
+        calendar_url: U,
+        username: N,
+        password: P,
+        options: &ClientOptions,
+    ) -> Result<Self> {
+        let client = options.apply(ClientBuilder::new()).build()?;
+        Self::from_client(calendar_url, username, password, client)
+    }
+
+    /// Create a new client for the calendar collection at the given url,
+    /// sending the requests with the given HTTP client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use aurion_rs::sync::caldav::CalDavClient;
+    /// use reqwest::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    /// let client = CalDavClient::from_client(
+    ///     "https://cloud.example.com/remote.php/dav/calendars/john/aurion/",
+    ///     "john",
+    ///     "app-password",
+    ///     client,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_client<U: AsRef<str>, N: Into<String>, P: Into<String>>(
+        calendar_url: U,
+        username: N,
+        password: P,
+        client: Client,
+    ) -> Result<Self> {
+        // The collection url must end with a slash for the resources to be
+        // resolved inside it
+        let mut calendar_url = calendar_url.as_ref().to_string();
+        if !calendar_url.ends_with('/') {
+            calendar_url.push('/');
+        }
+
+        Ok(Self {
+            calendar_url: Url::parse(&calendar_url)?,
+            username: username.into(),
+            password: password.into(),
+            client,
+            translations: Translations::new(),
+        })
+    }
+
+    /// Translate the subjects of the pushed events with the given
+    /// translations, for example [`Aurion::translations`](crate::Aurion::translations).
+    pub fn translations(mut self, translations: Translations) -> Self {
+        self.translations = translations;
+        self
+    }
+
+    /// Get the url of the resource storing the event with the given UID.
+    ///
+    /// The UID is percent-encoded as a single path segment, so that UIDs
+    /// holding characters such as `/` or `#` stay inside the collection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::sync::caldav::CalDavClient;
+    ///
+    /// let client = CalDavClient::new(
+    ///     "https://cloud.example.com/remote.php/dav/calendars/john/aurion",
+    ///     "john",
+    ///     "app-password",
+    /// )
+    /// .unwrap();
+    ///
+    /// let url = client.event_url("a/b#c?d-5ea3a0232928551d@aurion_rs").unwrap();
+    /// assert_eq!(
+    ///     url.as_str(),
+    ///     "https://cloud.example.com/remote.php/dav/calendars/john/aurion/\
+    ///      a%2Fb%23c%3Fd-5ea3a0232928551d@aurion_rs.ics"
+    /// );
+    /// ```
+    pub fn event_url(&self, uid: &str) -> Result<Url> {
+        let mut url = self.calendar_url.clone();
+        match url.path_segments_mut() {
+            Ok(mut segments) => {
+                segments.pop_if_empty().push(&format!("{}.ics", uid));
+            }
+            Err(()) => {
+                let message = format!("Invalid calendar url: {}", self.calendar_url);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        }
+        Ok(url)
+    }
+
+    /// Push an event to the calendar, creating or replacing it.
+    ///
+    /// Returns whether the event was created.
+    pub async fn push_event<S: AsRef<str>>(&self, event: &Event, scope: S) -> Result<bool> {
+        let url = self.event_url(&event_uid(event, scope.as_ref()))?;
+        debug!("Pushing event {} to {}", event.id, url);
+
+        let response = self
+            .client
+            .put(url)
+            .basic_auth(&self.username, Some(&self.password))
+            .header(CONTENT_TYPE, "text/calendar; charset=utf-8")
+            .body(ics::to_event_translated(event, scope, &self.translations))
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::CREATED => Ok(true),
+            status if status.is_success() => Ok(false),
+            status => {
+                let message = format!("Failed to push event {}: {}", event.id, status);
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        }
+    }
+
+    /// Push events to the calendar, creating or replacing them.
+    pub async fn push<S: AsRef<str>>(&self, events: &[Event], scope: S) -> Result<SyncReport> {
+        let mut report = SyncReport::default();
+        for event in events {
+            match self.push_event(event, scope.as_ref()).await? {
+                true => report.created += 1,
+                false => report.updated += 1,
+            }
+        }
+
+        debug!("Pushed events: {:?}", report);
+        Ok(report)
+    }
+
+    /// Delete an event from the calendar, for example once it was cancelled.
+    ///
+    /// Returns whether the event was in the calendar.
+    pub async fn delete_event<S: AsRef<str>>(&self, event: &Event, scope: S) -> Result<bool> {
+        let url = self.event_url(&event_uid(event, scope))?;
+        debug!("Deleting event {} from {}", event.id, url);
+
+        let response = self
+            .client
+            .request(Method::DELETE, url)
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(false),
+            status if status.is_success() => Ok(true),
+            status => {
+                let message = format!("Failed to delete event {}: {}", event.id, status);
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod caldav;
+#[cfg(feature = "store")]
+pub mod store;
@@ -0,0 +1,285 @@
+#![deny(missing_docs)]
+
+//! Keep a local SQLite copy of the user's schedule, synced incrementally.
+//!
+//! Every sync fetches the events of a date range, upserts them in the store
+//! and records a tombstone for every stored event of the range that is no
+//! longer listed, so that the schedule stays available offline and the
+//! changes since the last sync can be reported.
+//!
+//! Events are stored along with the version of the event schema they were
+//! serialized with, and migrated when read like [`Snapshot`]s.
+//!
+//! This module requires the `store` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use aurion_rs::Aurion;
+//! use aurion_rs::schedule::DateRange;
+//! use aurion_rs::sync::store::EventStore;
+//! use chrono::{Duration, Utc};
+//!
+//! # async fn run(aurion: Aurion) -> anyhow::Result<()> {
+//! let mut store = EventStore::open("schedule.sqlite")?;
+//!
+//! let range = DateRange::new(Utc::now(), Utc::now() + Duration::weeks(4));
+//! let changes = store.sync(&aurion, &range).await?;
+//! println!("{} added, {} removed", changes.added.len(), changes.removed.len());
+//! #     Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection};
+use tracing::{debug, error};
+
+use crate::aurion::Aurion;
+use crate::event::{Event, EventId, Snapshot, EVENT_SCHEMA_VERSION};
+use crate::schedule::{diff, DateRange, ScheduleDiff};
+use crate::transport::Transport;
+
+/// A stored event that was removed from the schedule.
+#[derive(Debug, Clone)]
+pub struct Tombstone {
+    /// The last known version of the event.
+    pub event: Event,
+
+    /// When the event was found to be removed.
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// A local SQLite store of events.
+pub struct EventStore {
+    connection: Connection,
+}
+
+impl EventStore {
+    /// Open the store at the given path, creating it if needed.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Open a store kept in memory, lost once dropped.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    /// Create the tables of the store if needed.
+    fn from_connection(connection: Connection) -> Result<Self> {
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS events (
+                id TEXT PRIMARY KEY,
+                start TEXT NOT NULL,
+                end TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                data TEXT NOT NULL,
+                deleted_at TEXT
+            );
+            CREATE INDEX IF NOT EXISTS events_dates ON events (start, end);",
+        )?;
+
+        Ok(Self { connection })
+    }
+
+    /// Get the stored events overlapping the given range, sorted by start
+    /// date. Removed events are not included.
+    pub fn events(&self, range: &DateRange) -> Result<Vec<Event>> {
+        let mut statement = self.connection.prepare(
+            "SELECT version, data FROM events
+            WHERE deleted_at IS NULL AND start < ?1 AND end > ?2
+            ORDER BY start",
+        )?;
+        let rows = statement.query_map(
+            params![format_date(range.end), format_date(range.start)],
+            |row| Ok((row.get::<_, u32>(0)?, row.get::<_, String>(1)?)),
+        )?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            let (version, data) = row?;
+            events.push(read_event(version, &data)?);
+        }
+
+        Ok(events)
+    }
+
+    /// Get the tombstones of the events removed from the schedule, the most
+    /// recently removed first.
+    pub fn tombstones(&self) -> Result<Vec<Tombstone>> {
+        let mut statement = self.connection.prepare(
+            "SELECT version, data, deleted_at FROM events
+            WHERE deleted_at IS NOT NULL
+            ORDER BY deleted_at DESC",
+        )?;
+        let rows = statement.query_map([], |row| {
+            Ok((
+                row.get::<_, u32>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut tombstones = Vec::new();
+        for row in rows {
+            let (version, data, deleted_at) = row?;
+            tombstones.push(Tombstone {
+                event: read_event(version, &data)?,
+                deleted_at: DateTime::parse_from_rfc3339(&deleted_at)?.with_timezone(&Utc),
+            });
+        }
+
+        Ok(tombstones)
+    }
+
+    /// Delete the tombstones of the events removed before the given date.
+    ///
+    /// Returns the number of deleted tombstones.
+    pub fn purge_tombstones(&mut self, before: DateTime<Utc>) -> Result<usize> {
+        Ok(self.connection.execute(
+            "DELETE FROM events WHERE deleted_at IS NOT NULL AND deleted_at < ?1",
+            params![format_date(before)],
+        )?)
+    }
+
+    /// Fetch the user's schedule of the given range and store it.
+    ///
+    /// See [`EventStore::apply`].
+    pub async fn sync<T: Transport>(
+        &mut self,
+        aurion: &Aurion<T>,
+        range: &DateRange,
+    ) -> Result<ScheduleDiff> {
+        let events = aurion
+            .get_user_schedule(Some(range.start), Some(range.end))
+            .await?;
+        self.apply(range, &events)
+    }
+
+    /// Store the freshly fetched events of the given range.
+    ///
+    /// The events are upserted, and the stored events of the range that are
+    /// not listed anymore are marked as removed. Returns the changes compared
+    /// to the stored events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::event::{Event, EventKind};
+    /// use aurion_rs::schedule::DateRange;
+    /// use aurion_rs::sync::store::EventStore;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let event = |id: u32, start: u32| Event {
+    ///     id: id.into(),
+    ///     kind: EventKind::Course,
+    ///     start: Utc.with_ymd_and_hms(2023, 3, 6, start, 0, 0).unwrap(),
+    ///     end: Utc.with_ymd_and_hms(2023, 3, 6, start + 2, 0, 0).unwrap(),
+    ///     all_day: false,
+    ///     editable: false,
+    ///     rooms: vec!["B101".into()],
+    ///     is_remote: false,
+    ///     subject: "Mathematics".to_string(),
+    ///     chapter: None,
+    ///     participants: vec![],
+    ///     group_id: None,
+    ///     diagnostic: None,
+    ///     raw_title: String::new(),
+    ///     raw_class_name: String::new(),
+    /// };
+    /// let range = DateRange::new(
+    ///     Utc.with_ymd_and_hms(2023, 3, 6, 0, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2023, 3, 7, 0, 0, 0).unwrap(),
+    /// );
+    ///
+    /// let mut store = EventStore::open_in_memory().unwrap();
+    /// let changes = store.apply(&range, &[event(1, 8), event(2, 10)]).unwrap();
+    /// assert_eq!(changes.added.len(), 2);
+    ///
+    /// // The second event was cancelled and the first one moved
+    /// let changes = store.apply(&range, &[event(1, 14)]).unwrap();
+    /// assert_eq!(changes.moved.len(), 1);
+    /// assert_eq!(changes.removed.len(), 1);
+    ///
+    /// assert_eq!(store.events(&range).unwrap().len(), 1);
+    /// assert_eq!(store.tombstones().unwrap()[0].event.id.as_str(), "2");
+    /// ```
+    pub fn apply(&mut self, range: &DateRange, events: &[Event]) -> Result<ScheduleDiff> {
+        let stored = self.events(range)?;
+        let changes = diff(&stored, events);
+        debug!(
+            "Storing {} events, {} removed",
+            events.len(),
+            changes.removed.len()
+        );
+
+        let transaction = self.connection.transaction()?;
+        for event in events {
+            transaction.execute(
+                "INSERT INTO events (id, start, end, version, data, deleted_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, NULL)
+                ON CONFLICT (id) DO UPDATE SET
+                    start = excluded.start,
+                    end = excluded.end,
+                    version = excluded.version,
+                    data = excluded.data,
+                    deleted_at = NULL",
+                params![
+                    event.id.as_str(),
+                    format_date(event.start),
+                    format_date(event.end),
+                    EVENT_SCHEMA_VERSION,
+                    serde_json::to_string(event)?,
+                ],
+            )?;
+        }
+
+        let now = format_date(Utc::now());
+        for event in &changes.removed {
+            transaction.execute(
+                "UPDATE events SET deleted_at = ?1 WHERE id = ?2",
+                params![now, event.id.as_str()],
+            )?;
+        }
+        transaction.commit()?;
+
+        Ok(changes)
+    }
+
+    /// Get a stored event by its id, even if it was removed.
+    pub fn get(&self, event_id: &EventId) -> Result<Option<Event>> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT version, data FROM events WHERE id = ?1")?;
+        let mut rows = statement.query(params![event_id.as_str()])?;
+
+        match rows.next()? {
+            Some(row) => Ok(Some(read_event(row.get(0)?, &row.get::<_, String>(1)?)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Format a date so that dates can be compared as strings.
+fn format_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Deserialize a stored event, migrating it from the given schema version.
+fn read_event(version: u32, data: &str) -> Result<Event> {
+    let snapshot = Snapshot::from_json(format!(
+        r#"{{"version": {}, "events": [{}]}}"#,
+        version, data
+    ))?;
+    match snapshot.events.into_iter().next() {
+        Some(event) => Ok(event),
+        None => {
+            let message = "Failed to read stored event: event not found".to_string();
+            error!("{}", message);
+            Err(Error::msg(message))
+        }
+    }
+}
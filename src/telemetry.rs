@@ -0,0 +1,69 @@
+//! Metrics recorded through the `metrics` facade when the `metrics` feature
+//! is enabled, and no-ops otherwise.
+//!
+//! The recorded metrics are:
+//! - `aurion_requests_total`: counter of the requests sent, labelled by
+//!   `method`, `page` and `status` (`error` when the transport failed);
+//! - `aurion_request_duration_seconds`: histogram of the request latencies,
+//!   labelled by `page`;
+//! - `aurion_parse_failures_total`: counter of the responses that could not
+//!   be parsed, labelled by `kind`;
+//! - `aurion_login_attempts_total`: counter of the login attempts, labelled
+//!   by `outcome` (`success` or `rejected`).
+
+#![cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use reqwest::Url;
+
+/// Record a request sent to Aurion.
+pub fn record_request(method: &Method, url: &Url, status: Option<StatusCode>, duration: Duration) {
+    #[cfg(feature = "metrics")]
+    {
+        let page = page_name(url);
+        let status = match status {
+            Some(status) => status.as_u16().to_string(),
+            None => "error".to_string(),
+        };
+        metrics::counter!(
+            "aurion_requests_total",
+            "method" => method.to_string(),
+            "page" => page.clone(),
+            "status" => status,
+        )
+        .increment(1);
+        metrics::histogram!("aurion_request_duration_seconds", "page" => page)
+            .record(duration.as_secs_f64());
+    }
+}
+
+/// Record a response that could not be parsed.
+pub fn record_parse_failure(kind: &'static str) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("aurion_parse_failures_total", "kind" => kind).increment(1);
+}
+
+/// Record a login attempt.
+pub fn record_login(success: bool) {
+    #[cfg(feature = "metrics")]
+    {
+        let outcome = match success {
+            true => "success",
+            false => "rejected",
+        };
+        metrics::counter!("aurion_login_attempts_total", "outcome" => outcome).increment(1);
+    }
+}
+
+/// Get the name of the page of a url, its last path segment, which keeps
+/// the number of label values bounded.
+#[cfg(feature = "metrics")]
+fn page_name(url: &Url) -> String {
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|segment| !segment.is_empty())
+        .unwrap_or("/")
+        .to_string()
+}
@@ -0,0 +1,169 @@
+//! Helpers shared by the unit tests.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, Utc};
+use http::header::LOCATION;
+use http::{Method, StatusCode};
+use reqwest::Url;
+use serde_json::{json, Value};
+
+use crate::aurion::Aurion;
+use crate::aurion_builder::AurionBuilder;
+use crate::transport::{Request, Response, RetryPolicy, Transport};
+
+/// The id of the schedule form of the planning page served by
+/// [`FakeTransport`].
+const SCHEDULE_FORM: &str = "form:j_idt117";
+
+/// A transport standing in for Aurion, serving the user planning with the
+/// given events until it is made to fail.
+///
+/// Like Aurion, the details of an event can only be selected among the
+/// events served last.
+#[derive(Clone, Default)]
+pub struct FakeTransport {
+    events: Arc<Vec<Value>>,
+    served: Arc<Mutex<Vec<String>>>,
+    failing: Arc<AtomicBool>,
+    failures: Arc<AtomicUsize>,
+}
+
+impl FakeTransport {
+    /// Create a transport serving the given raw events, see [`event`].
+    pub fn new(events: Vec<Value>) -> Self {
+        Self {
+            events: Arc::new(events),
+            ..Self::default()
+        }
+    }
+
+    /// Make the next requests fail, or succeed again.
+    #[cfg(any(feature = "feed-server", feature = "refresher"))]
+    pub fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+
+    /// Get the number of requests that failed.
+    #[cfg(any(feature = "feed-server", feature = "refresher"))]
+    pub fn failures(&self) -> usize {
+        self.failures.load(Ordering::SeqCst)
+    }
+
+    /// Answer a request for the events between the requested dates.
+    fn schedule(&self, form: &Value) -> Response {
+        let bound = |name: &str| {
+            form[format!("{}_{}", SCHEDULE_FORM, name)]
+                .as_i64()
+                .and_then(DateTime::from_timestamp_millis)
+                .unwrap_or_default()
+        };
+        let (start, end) = (bound("start"), bound("end"));
+
+        let events = self
+            .events
+            .iter()
+            .filter(|event| {
+                let date = |name: &str| {
+                    DateTime::parse_from_rfc3339(event[name].as_str().unwrap_or_default()).ok()
+                };
+                date("start").is_some_and(|date| date < end)
+                    && date("end").is_some_and(|date| date > start)
+            })
+            .collect::<Vec<&Value>>();
+        *self.served.lock().unwrap() = events
+            .iter()
+            .map(|event| event["id"].as_str().unwrap_or_default().to_string())
+            .collect();
+
+        Response::new(
+            StatusCode::OK,
+            format!(
+                "<partial-response><changes><update id=\"{}\"><![CDATA[{{\"events\" : {}}}]]></update></changes></partial-response>",
+                SCHEDULE_FORM,
+                Value::from(events.into_iter().cloned().collect::<Vec<Value>>())
+            ),
+        )
+    }
+
+    /// Answer the selection of an event, if it was served last.
+    fn details(&self, event_id: &str) -> Response {
+        if !self.served.lock().unwrap().iter().any(|id| id == event_id) {
+            return Response::new(StatusCode::OK, "<partial-response></partial-response>");
+        }
+
+        Response::new(
+            StatusCode::OK,
+            "<partial-response><changes><update id=\"form:modaleDetail\"><![CDATA[<div></div>]]></update></changes></partial-response>",
+        )
+    }
+}
+
+impl Transport for FakeTransport {
+    async fn send(&self, request: &Request) -> Result<Response> {
+        if self.failing.load(Ordering::SeqCst) {
+            self.failures.fetch_add(1, Ordering::SeqCst);
+            bail!("Connection refused");
+        }
+
+        let page = request.url.path().rsplit('/').next().unwrap_or_default();
+        Ok(match (&request.method, page, &request.form) {
+            // Navigation to the user planning
+            (&Method::POST, "MainMenuPage.xhtml", _) => Response::new(StatusCode::FOUND, "")
+                .with_header(LOCATION, "https://aurion.example/faces/Planning.xhtml"),
+            // Planning page
+            (&Method::GET, _, _) => Response::new(
+                StatusCode::OK,
+                format!(
+                    r#"<div id="{}" class="schedule"></div>
+                       <input name="javax.faces.ViewState" value="42:0" />"#,
+                    SCHEDULE_FORM
+                ),
+            ),
+            // Details of an event, or events of the planning
+            (_, _, Some(form)) => match form[format!("{}_selectedEventId", SCHEDULE_FORM)].as_str()
+            {
+                Some(event_id) => self.details(event_id),
+                None => self.schedule(form),
+            },
+            _ => Response::new(StatusCode::BAD_REQUEST, ""),
+        })
+    }
+
+    fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+}
+
+/// Create a raw event as served by Aurion, lasting two hours from `start`.
+pub fn event(id: &str, start: DateTime<Utc>) -> Value {
+    json!({
+        "id": id,
+        "title": "08h00 à 10h00 - B101 - Cours - Mathematics - Vectors - DOE John",
+        "start": start.to_rfc3339(),
+        "end": (start + Duration::hours(2)).to_rfc3339(),
+        "allDay": false,
+        "editable": false,
+        "className": "CM",
+    })
+}
+
+/// Create a session sending its requests through the given transport, whose
+/// user planning node is already known.
+pub fn aurion(transport: FakeTransport) -> Aurion<FakeTransport> {
+    let mut aurion = AurionBuilder::new(
+        275805,
+        "submenu_291906",
+        "1_3",
+        "submenu_299102",
+        "https://aurion.example/",
+    )
+    .retry_policy(RetryPolicy::none())
+    .transport(transport)
+    .build();
+    aurion
+        .menu_mut()
+        .add_child_node("submenu_291906", "1_3", "1_3", "My planning")
+        .unwrap();
+    aurion
+}
@@ -0,0 +1,35 @@
+#![deny(missing_docs)]
+
+use http::{HeaderMap, HeaderValue};
+use reqwest::ClientBuilder;
+
+use super::ProxyConfig;
+
+/// The options of the HTTP client of a [`ReqwestTransport`](super::ReqwestTransport).
+#[derive(Debug, Clone, Default)]
+pub struct ClientOptions {
+    /// The proxy the requests are sent through, if any.
+    pub proxy: Option<ProxyConfig>,
+
+    /// The User-Agent header sent instead of the reqwest default one.
+    pub user_agent: Option<HeaderValue>,
+
+    /// The headers sent with every request.
+    pub headers: HeaderMap,
+}
+
+impl ClientOptions {
+    /// Apply the options to a client builder.
+    pub(crate) fn apply(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.to_proxy().expect("Proxy url was validated"));
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+        if !self.headers.is_empty() {
+            builder = builder.default_headers(self.headers.clone());
+        }
+        builder
+    }
+}
@@ -0,0 +1,56 @@
+#![deny(missing_docs)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A cookie of the Aurion session, such as the `JSESSIONID` authentication
+/// cookie.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::transport::Cookie;
+///
+/// let cookies = Cookie::parse_list("JSESSIONID=0123; lang=fr");
+/// assert_eq!(cookies[0], Cookie::new("JSESSIONID", "0123"));
+/// assert_eq!(cookies[1].to_string(), "lang=fr");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cookie {
+    /// The name of the cookie.
+    pub name: String,
+
+    /// The value of the cookie.
+    pub value: String,
+}
+
+impl Cookie {
+    /// Create a new cookie.
+    pub fn new<N: Into<String>, V: Into<String>>(name: N, value: V) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Parse the cookies of a `Cookie` header value, of the form
+    /// `name=value; name=value`.
+    ///
+    /// Pairs without a `=` are skipped.
+    pub fn parse_list<T: AsRef<str>>(header: T) -> Vec<Self> {
+        header
+            .as_ref()
+            .split(';')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(name, value)| Self::new(name.trim(), value.trim()))
+            .filter(|cookie| !cookie.name.is_empty())
+            .collect()
+    }
+}
+
+impl fmt::Display for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
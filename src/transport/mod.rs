@@ -0,0 +1,25 @@
+mod client_options;
+mod cookie;
+mod proxy_config;
+mod request_hook;
+mod request_timing;
+mod reqwest_transport;
+mod retry_policy;
+mod timeouts;
+mod transport;
+
+pub use client_options::ClientOptions;
+pub use cookie::Cookie;
+pub use proxy_config::ProxyConfig;
+pub(crate) use request_hook::redact_headers;
+pub(crate) use request_hook::redact_request;
+pub(crate) use request_hook::zeroize_form;
+pub use request_hook::Exchange;
+pub use request_hook::RequestHook;
+pub use request_timing::RequestTiming;
+pub use reqwest_transport::ReqwestTransport;
+pub use retry_policy::RetryPolicy;
+pub use timeouts::Timeouts;
+pub use transport::Request;
+pub use transport::Response;
+pub use transport::Transport;
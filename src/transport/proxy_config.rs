@@ -0,0 +1,70 @@
+#![deny(missing_docs)]
+
+use anyhow::{Error, Result};
+use reqwest::{Proxy, Url};
+use tracing::error;
+
+/// The schemes of the supported proxies.
+const PROXY_SCHEMES: [&str; 4] = ["http", "https", "socks5", "socks5h"];
+
+/// The configuration of the proxy the requests are sent through, an HTTP or
+/// a SOCKS5 proxy.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::transport::ProxyConfig;
+///
+/// let proxy = ProxyConfig::new("socks5://proxy.school.example:1080")
+///     .unwrap()
+///     .credentials("john", "secret");
+///
+/// assert!(ProxyConfig::new("ftp://proxy.school.example").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    url: Url,
+    credentials: Option<(String, String)>,
+}
+
+impl ProxyConfig {
+    /// Create a proxy configuration from the proxy url, whose scheme is one
+    /// of `http`, `https`, `socks5` or `socks5h`.
+    pub fn new<U: AsRef<str>>(url: U) -> Result<Self> {
+        let url = Url::parse(url.as_ref())?;
+        if !PROXY_SCHEMES.contains(&url.scheme()) {
+            let message = format!("Unsupported proxy scheme: {}", url.scheme());
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        Ok(Self {
+            url,
+            credentials: None,
+        })
+    }
+
+    /// Authenticate to the proxy with the given credentials.
+    pub fn credentials<U: Into<String>, P: Into<String>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Get the url of the proxy.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Build the reqwest proxy, routing every request through the proxy.
+    pub(crate) fn to_proxy(&self) -> reqwest::Result<Proxy> {
+        let proxy = Proxy::all(self.url.clone())?;
+        Ok(match &self.credentials {
+            Some((username, password)) => proxy.basic_auth(username, password),
+            None => proxy,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+#![deny(missing_docs)]
+
+use http::header::{AUTHORIZATION, COOKIE, SET_COOKIE};
+use http::{HeaderMap, HeaderValue};
+use serde_json::Value;
+use zeroize::Zeroize;
+
+use super::{Request, RequestTiming};
+
+/// The value replacing the redacted credentials.
+const REDACTED: &str = "[REDACTED]";
+
+/// The parts of the names of the form fields holding credentials: passwords,
+/// and one-time codes named like the inputs of the two-factor challenges.
+const CREDENTIAL_FIELDS: [&str; 4] = ["password", "otp", "code", "token"];
+
+/// A hook invoked around every request sent to Aurion, for example to log
+/// the exchanges while debugging a JSF page in production.
+///
+/// The hooks only see redacted copies of the exchanges: the password and
+/// one-time code form fields and the cookie and authorization headers are replaced by
+/// `[REDACTED]`. Every method has a default implementation doing nothing, so
+/// hooks only implement the ones they need.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+///
+/// use aurion_rs::transport::{Exchange, Request, RequestHook, Response, Transport};
+/// use aurion_rs::AurionBuilder;
+/// use http::header::SET_COOKIE;
+/// use http::StatusCode;
+/// use reqwest::Url;
+///
+/// struct RejectingTransport;
+///
+/// impl Transport for RejectingTransport {
+///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+///         Ok(Response::new(StatusCode::OK, "").with_header(SET_COOKIE, "JSESSIONID=0123"))
+///     }
+///
+///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+/// }
+///
+/// #[derive(Default)]
+/// struct RecordingHook {
+///     requests: Arc<Mutex<Vec<Request>>>,
+///     exchanges: Arc<Mutex<Vec<Exchange>>>,
+/// }
+///
+/// impl RequestHook for RecordingHook {
+///     fn before_request(&self, request: &Request, _attempt: u32) {
+///         self.requests.lock().unwrap().push(request.clone());
+///     }
+///
+///     fn after_response(&self, exchange: &Exchange) {
+///         self.exchanges.lock().unwrap().push(exchange.clone());
+///     }
+///
+///     fn include_bodies(&self) -> bool {
+///         true
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let hook = RecordingHook::default();
+/// let (requests, exchanges) = (hook.requests.clone(), hook.exchanges.clone());
+///
+/// let mut aurion = AurionBuilder::new(
+///     275805,
+///     "submenu_291906",
+///     "1_3",
+///     "submenu_299102",
+///     "https://aurion.example/",
+/// )
+/// .transport(RejectingTransport)
+/// .hook(hook)
+/// .build();
+/// assert!(aurion.login("username", "password").await.is_err());
+///
+/// let form = requests.lock().unwrap()[0].form.clone().unwrap();
+/// assert_eq!(form["username"], "username");
+/// assert_eq!(form["password"], "[REDACTED]");
+///
+/// let headers = exchanges.lock().unwrap()[0].headers.clone().unwrap();
+/// assert_eq!(headers[SET_COOKIE], "[REDACTED]");
+/// # }
+/// ```
+pub trait RequestHook: Send + Sync {
+    /// Called before every attempt to send a request.
+    ///
+    /// The form of the request is only given if `include_bodies` is set.
+    fn before_request(&self, request: &Request, attempt: u32) {
+        let _ = (request, attempt);
+    }
+
+    /// Called after every attempt to send a request, whether it succeeded
+    /// or not.
+    fn after_response(&self, exchange: &Exchange) {
+        let _ = exchange;
+    }
+
+    /// Whether the request forms and the response bodies are given to the
+    /// hook. Defaults to `false`.
+    fn include_bodies(&self) -> bool {
+        false
+    }
+}
+
+/// The outcome of an attempt to send a request, given to the hooks.
+#[derive(Debug, Clone)]
+pub struct Exchange {
+    /// The method, url, status and duration of the attempt.
+    pub timing: RequestTiming,
+
+    /// The headers of the response, with the cookies redacted.
+    pub headers: Option<HeaderMap>,
+
+    /// The body of the response, only given if `include_bodies` is set.
+    pub body: Option<String>,
+
+    /// The error of the transport, if the request could not be sent.
+    pub error: Option<String>,
+}
+
+/// Copy a request with its credential fields redacted, and its form dropped
+/// unless the bodies are included.
+pub(crate) fn redact_request(request: &Request, include_bodies: bool) -> Request {
+    let mut request = request.clone();
+    request.form = match include_bodies {
+        true => request.form.map(redact_form),
+        false => None,
+    };
+    request
+}
+
+/// Redact the password and one-time code fields of a form.
+pub(crate) fn redact_form(mut form: Value) -> Value {
+    if let Value::Object(fields) = &mut form {
+        for (name, value) in fields.iter_mut() {
+            let name = name.to_lowercase();
+            if CREDENTIAL_FIELDS.iter().any(|field| name.contains(field)) {
+                zeroize_value(value);
+                *value = Value::String(REDACTED.to_string());
+            }
+        }
+    }
+    form
+}
+
+/// Overwrite the strings of a form with zeroes, so that the credentials it
+/// may hold don't linger in memory once it is dropped.
+pub(crate) fn zeroize_form(form: &mut Option<Value>) {
+    if let Some(form) = form {
+        zeroize_value(form);
+    }
+}
+
+/// Overwrite the strings of a JSON value with zeroes.
+fn zeroize_value(value: &mut Value) {
+    match value {
+        Value::String(string) => string.zeroize(),
+        Value::Array(values) => values.iter_mut().for_each(zeroize_value),
+        Value::Object(fields) => fields.values_mut().for_each(zeroize_value),
+        _ => {}
+    }
+}
+
+/// Copy headers with their cookie and authorization values redacted.
+pub(crate) fn redact_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    for name in [SET_COOKIE, COOKIE, AUTHORIZATION] {
+        if let http::header::Entry::Occupied(mut entry) = headers.entry(name) {
+            let count = entry.iter().count();
+            entry.insert(HeaderValue::from_static(REDACTED));
+            for _ in 1..count {
+                entry.append(HeaderValue::from_static(REDACTED));
+            }
+        }
+    }
+    headers
+}
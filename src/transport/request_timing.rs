@@ -0,0 +1,26 @@
+#![deny(missing_docs)]
+
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use reqwest::Url;
+
+/// The timing of a request sent to Aurion.
+#[derive(Debug, Clone)]
+pub struct RequestTiming {
+    /// The method of the request.
+    pub method: Method,
+
+    /// The url of the request.
+    pub url: Url,
+
+    /// The status code of the response, if the transport didn't fail.
+    pub status: Option<StatusCode>,
+
+    /// The attempt of the request, starting from 1, as failed idempotent
+    /// requests are retried.
+    pub attempt: u32,
+
+    /// The time spent waiting for the response.
+    pub duration: Duration,
+}
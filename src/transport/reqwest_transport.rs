@@ -0,0 +1,124 @@
+#![deny(missing_docs)]
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use http::header::{COOKIE, SET_COOKIE};
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::redirect::Policy;
+use reqwest::{Client, ClientBuilder, Url};
+
+use super::{ClientOptions, Cookie, Request, Response, Transport};
+
+/// The default transport, backed by a [`reqwest::Client`] with a cookie store.
+pub struct ReqwestTransport {
+    client: Client,
+    cookie_store: Arc<Jar>,
+    /// Whether the cookies are sent and stored by the transport instead of
+    /// the client, when the client wasn't built with the cookie store.
+    manage_cookies: bool,
+}
+
+impl ReqwestTransport {
+    /// Create a new transport with an empty cookie store.
+    pub fn new() -> Self {
+        Self::with_options(&ClientOptions::default())
+    }
+
+    /// Create a new transport with an empty cookie store, whose client is
+    /// configured with the given options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend can't be initialized, like
+    /// [`reqwest::Client::new`].
+    pub fn with_options(options: &ClientOptions) -> Self {
+        let cookie_store = Arc::new(Jar::default());
+        let builder = options.apply(
+            ClientBuilder::new()
+                .redirect(Policy::none())
+                .cookie_provider(Arc::clone(&cookie_store)),
+        );
+
+        Self {
+            client: builder.build().unwrap(),
+            cookie_store,
+            manage_cookies: false,
+        }
+    }
+
+    /// Create a new transport with an empty cookie store, sending the
+    /// requests with the given client.
+    ///
+    /// The session cookies are kept by the transport, so the client must not
+    /// have a cookie store of its own. It must not follow redirections
+    /// either, as Aurion relies on them to signal a successful navigation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use aurion_rs::transport::ReqwestTransport;
+    /// use reqwest::redirect::Policy;
+    /// use reqwest::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .redirect(Policy::none())
+    ///     .pool_idle_timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    /// let transport = ReqwestTransport::from_client(client);
+    /// ```
+    pub fn from_client(client: Client) -> Self {
+        Self {
+            client,
+            cookie_store: Arc::new(Jar::default()),
+            manage_cookies: true,
+        }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: &Request) -> Result<Response> {
+        let url = &request.url;
+        let mut builder = self.client.request(request.method.clone(), url.clone());
+        if let Some(form) = &request.form {
+            builder = builder.form(form);
+        }
+        if self.manage_cookies {
+            if let Some(cookies) = self.cookie_store.cookies(url) {
+                builder = builder.header(COOKIE, cookies);
+            }
+        }
+
+        let response = builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        if self.manage_cookies {
+            self.cookie_store
+                .set_cookies(&mut headers.get_all(SET_COOKIE).iter(), url);
+        }
+        let bytes = response.bytes().await?;
+
+        let mut response = Response::from_bytes(status, bytes);
+        response.headers = headers;
+        Ok(response)
+    }
+
+    fn add_cookie(&self, cookie: &str, url: &Url) {
+        self.cookie_store.add_cookie_str(cookie, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Vec<Cookie> {
+        self.cookie_store
+            .cookies(url)
+            .and_then(|header| header.to_str().ok().map(Cookie::parse_list))
+            .unwrap_or_default()
+    }
+}
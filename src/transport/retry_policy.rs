@@ -0,0 +1,80 @@
+#![deny(missing_docs)]
+
+use std::time::Duration;
+
+/// The policy used to retry failed requests.
+///
+/// Only idempotent requests are retried, and only when the transport failed
+/// or when the server answered with a server error (`5xx`). The delay between
+/// two attempts grows exponentially from `initial_backoff` up to
+/// `max_backoff`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// use aurion_rs::transport::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     jitter: false,
+///     ..RetryPolicy::default()
+/// };
+/// assert_eq!(policy.backoff(1), Duration::from_millis(500));
+/// assert_eq!(policy.backoff(2), Duration::from_millis(1000));
+/// assert_eq!(policy.backoff(10), Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+
+    /// The factor applied to the delay after each retry.
+    pub multiplier: f64,
+
+    /// The maximum delay between two attempts.
+    pub max_backoff: Duration,
+
+    /// Whether to randomize the delays, so that multiple clients do not
+    /// retry at the same time.
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Get the delay to wait after the given failed attempt (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let delay = delay.min(self.max_backoff.as_secs_f64());
+
+        // Keep at least half of the delay so that retries stay spaced out
+        let delay = match self.jitter {
+            true => delay / 2.0 + fastrand::f64() * delay / 2.0,
+            false => delay,
+        };
+
+        Duration::from_secs_f64(delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
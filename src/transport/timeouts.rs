@@ -0,0 +1,49 @@
+#![deny(missing_docs)]
+
+use std::time::Duration;
+
+/// The timeouts of the requests sent to Aurion.
+///
+/// A request that doesn't get its response in time fails with
+/// [`AurionError::Timeout`](crate::AurionError::Timeout), and is retried
+/// like any other failed request when it is idempotent. The operations that
+/// are known to be slow can be given a longer timeout than the other
+/// requests. No timeout is set by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// use aurion_rs::transport::Timeouts;
+///
+/// let timeouts = Timeouts {
+///     schedule: Some(Duration::from_secs(60)),
+///     ..Timeouts::new(Duration::from_secs(15))
+/// };
+/// assert_eq!(timeouts.request, Some(Duration::from_secs(15)));
+/// assert_eq!(timeouts.login, None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Timeouts {
+    /// The timeout of every request, unless overridden for its operation.
+    pub request: Option<Duration>,
+
+    /// The timeout of the requests sent to log in.
+    pub login: Option<Duration>,
+
+    /// The timeout of the requests fetching a schedule.
+    pub schedule: Option<Duration>,
+
+    /// The timeout of the requests downloading a document.
+    pub download: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Timeouts applying the given timeout to every request.
+    pub fn new(request: Duration) -> Self {
+        Self {
+            request: Some(request),
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,260 @@
+#![deny(missing_docs)]
+
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue, LOCATION};
+use http::{HeaderMap, Method, StatusCode};
+use reqwest::Url;
+use serde_json::Value;
+
+use super::request_hook::{redact_form, zeroize_form};
+use super::Cookie;
+
+/// An HTTP request sent to Aurion.
+///
+/// The password and one-time code fields of the form are redacted from the
+/// `Debug` output.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::transport::Request;
+/// use reqwest::Url;
+/// use serde_json::json;
+///
+/// let request = Request::post(
+///     Url::parse("https://aurion.example/login").unwrap(),
+///     json!({ "username": "username", "password": "hunter2" }),
+/// );
+///
+/// let debug = format!("{:?}", request);
+/// assert!(debug.contains("[REDACTED]"));
+/// assert!(!debug.contains("hunter2"));
+///
+/// let request = Request::post(
+///     Url::parse("https://aurion.example/login/mfa").unwrap(),
+///     json!({ "execution": "e1s2", "otpCode": "123456" }),
+/// );
+/// assert!(!format!("{:?}", request).contains("123456"));
+/// ```
+#[derive(Clone)]
+pub struct Request {
+    /// The method of the request.
+    pub method: Method,
+
+    /// The url of the request.
+    pub url: Url,
+
+    /// The payload of the request, sent url-encoded as a form.
+    pub form: Option<Value>,
+
+    /// Whether the request can safely be sent multiple times.
+    /// Only idempotent requests are retried on failure.
+    pub idempotent: bool,
+
+    /// The time after which the request is abandoned, overriding the
+    /// [`Timeouts`](super::Timeouts) of the session.
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    /// Create a new GET request.
+    pub fn get(url: Url) -> Self {
+        Self {
+            method: Method::GET,
+            url,
+            form: None,
+            idempotent: true,
+            timeout: None,
+        }
+    }
+
+    /// Create a new POST request with the given form payload.
+    pub fn post(url: Url, form: Value) -> Self {
+        Self {
+            method: Method::POST,
+            url,
+            form: Some(form),
+            idempotent: false,
+            timeout: None,
+        }
+    }
+
+    /// Mark the request as idempotent, for example a POST request that only
+    /// reads data.
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    /// Set the time after which the request is abandoned.
+    pub fn timeout<D: Into<Option<Duration>>>(mut self, timeout: D) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+}
+
+impl fmt::Debug for Request {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut form = self.form.clone().map(redact_form);
+        let result = f
+            .debug_struct("Request")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("form", &form)
+            .field("idempotent", &self.idempotent)
+            .field("timeout", &self.timeout)
+            .finish();
+        zeroize_form(&mut form);
+        result
+    }
+}
+
+/// An HTTP response received from Aurion.
+#[derive(Debug, Clone)]
+pub struct Response {
+    /// The status code of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The body of the response, decoded as text.
+    pub body: String,
+
+    /// The raw body of the response, for binary content such as downloaded
+    /// documents.
+    pub bytes: Bytes,
+}
+
+impl Response {
+    /// Create a new response with the given status code and body.
+    pub fn new<B: Into<String>>(status: StatusCode, body: B) -> Self {
+        let body = body.into();
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            bytes: Bytes::from(body.clone()),
+            body,
+        }
+    }
+
+    /// Create a new response with the given status code and raw body.
+    ///
+    /// The text body is decoded from the raw body, replacing invalid UTF-8
+    /// sequences.
+    pub fn from_bytes<B: Into<Bytes>>(status: StatusCode, bytes: B) -> Self {
+        let bytes = bytes.into();
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: String::from_utf8_lossy(&bytes).into_owned(),
+            bytes,
+        }
+    }
+
+    /// Add a header to the response.
+    ///
+    /// Invalid header names or values are ignored.
+    pub fn with_header<V: AsRef<str>>(mut self, name: HeaderName, value: V) -> Self {
+        if let Ok(value) = HeaderValue::from_str(value.as_ref()) {
+            self.headers.append(name, value);
+        }
+        self
+    }
+
+    /// Get the redirection location of the response, if any.
+    pub fn location(&self) -> Option<&str> {
+        self.headers.get(LOCATION)?.to_str().ok()
+    }
+}
+
+/// The HTTP layer used by [`Aurion`](crate::Aurion) to talk to the server.
+///
+/// The default implementation is [`ReqwestTransport`](super::ReqwestTransport).
+/// Implementing this trait allows to replace the network with canned
+/// responses, for example to test code using `Aurion` offline.
+///
+/// Implementations must keep the session cookies between requests and must
+/// not follow redirections, as Aurion relies on the `location` header to
+/// signal a successful navigation.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # use std::sync::Mutex;
+/// use aurion_rs::transport::{Request, Response, Transport};
+/// use aurion_rs::Aurion;
+/// use http::header::{LOCATION, SET_COOKIE};
+/// use http::StatusCode;
+/// use reqwest::Url;
+///
+/// struct CannedTransport {
+///     responses: Mutex<VecDeque<Response>>,
+/// }
+///
+/// impl Transport for CannedTransport {
+///     async fn send(&self, _request: &Request) -> anyhow::Result<Response> {
+///         Ok(self.responses.lock().unwrap().pop_front().unwrap())
+///     }
+///
+///     fn add_cookie(&self, _cookie: &str, _url: &Url) {}
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let main_page = Response::new(
+///     StatusCode::OK,
+///     "<input name=\"javax.faces.ViewState\" value=\"42:0\" />\
+///      chargerSousMenu = function() {PrimeFaces.ab({s:\"form:j_idt52\"",
+/// );
+/// let transport = CannedTransport {
+///     responses: Mutex::new(VecDeque::from([
+///         // Login
+///         Response::new(StatusCode::FOUND, "")
+///             .with_header(LOCATION, "https://aurion.example/")
+///             .with_header(SET_COOKIE, "JSESSIONID=0123"),
+///         main_page.clone(),
+///         // Language switch
+///         Response::new(StatusCode::OK, "<partial-response />"),
+///         main_page,
+///     ])),
+/// };
+///
+/// let mut aurion = Aurion::with_transport(
+///     275805,
+///     "submenu_291906",
+///     "1_3",
+///     "submenu_299102",
+///     "https://aurion.example/",
+///     transport,
+/// );
+/// let token = aurion.login("username", "password").await.unwrap();
+/// assert_eq!(token, "JSESSIONID=0123");
+/// # }
+/// ```
+pub trait Transport: Send + Sync {
+    /// Send the request and return the response of the server.
+    ///
+    /// The request is borrowed, so that its form, which may hold the login
+    /// credentials, is only overwritten with zeroes once by the caller after
+    /// the last attempt, rather than copied for every attempt.
+    fn send(&self, request: &Request) -> impl Future<Output = Result<Response>> + Send;
+
+    /// Add a cookie to the session, as if it was set by the server at `url`.
+    fn add_cookie(&self, cookie: &str, url: &Url);
+
+    /// Get the cookies of the session sent with the requests to `url`.
+    ///
+    /// Transports that can't list their cookies return none, which is the
+    /// default.
+    fn cookies(&self, url: &Url) -> Vec<Cookie> {
+        let _ = url;
+        Vec::new()
+    }
+}
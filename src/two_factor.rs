@@ -0,0 +1,141 @@
+#![deny(missing_docs)]
+
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+use hmac::{Hmac, Mac};
+use secrecy::{ExposeSecret, SecretString};
+use sha1::Sha1;
+use tracing::error;
+use zeroize::Zeroizing;
+
+/// The validity period of a TOTP code, in seconds.
+const TOTP_PERIOD: i64 = 30;
+
+/// The number of digits of a TOTP code.
+const TOTP_DIGITS: u32 = 6;
+
+/// A callback asked for the one-time code of a two-factor challenge.
+type CodeCallback = Arc<dyn Fn() -> BoxFuture<'static, Result<String>> + Send + Sync>;
+
+/// How the one-time code of a two-factor challenge is obtained on login, see
+/// [`AurionBuilder::two_factor`](crate::AurionBuilder::two_factor).
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::{AurionBuilder, TwoFactor};
+///
+/// let aurion = AurionBuilder::new(
+///     275805,
+///     "submenu_291906",
+///     "1_3",
+///     "submenu_299102",
+///     "https://web.isen-ouest.fr/webAurion/",
+/// )
+/// .two_factor(TwoFactor::callback(|| async {
+///     // Ask the user for the code sent to their phone
+///     Ok("123456".to_string())
+/// }))
+/// .build();
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum TwoFactor {
+    /// Generate the codes from the TOTP secret of the account, as shared
+    /// by the QR code of an authenticator app.
+    Totp {
+        /// The base32 encoded secret.
+        secret: SecretString,
+    },
+
+    /// Ask the codes to a callback, for example to prompt the user.
+    Callback(CodeCallback),
+}
+
+impl TwoFactor {
+    /// Generate the codes from the given base32 encoded TOTP secret.
+    pub fn totp<S: Into<SecretString>>(secret: S) -> Self {
+        TwoFactor::Totp {
+            secret: secret.into(),
+        }
+    }
+
+    /// Ask the codes to the given async callback.
+    pub fn callback<F, Fut>(callback: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String>> + Send + 'static,
+    {
+        TwoFactor::Callback(Arc::new(move || callback().boxed()))
+    }
+
+    /// Get the one-time code to answer a two-factor challenge with.
+    pub async fn code(&self) -> Result<String> {
+        match self {
+            TwoFactor::Totp { secret } => Self::totp_code(secret.expose_secret(), Utc::now()),
+            TwoFactor::Callback(callback) => callback().await,
+        }
+    }
+
+    /// Generate the TOTP code of the given base32 encoded secret at the given
+    /// time, as defined by RFC 6238 with its default parameters: HMAC-SHA1,
+    /// 30 seconds periods and 6 digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use aurion_rs::TwoFactor;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// // The secret "12345678901234567890" of the RFC 6238 test vectors
+    /// let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+    /// let time = Utc.timestamp_opt(59, 0).unwrap();
+    /// assert_eq!(TwoFactor::totp_code(secret, time).unwrap(), "287082");
+    ///
+    /// // Secrets are often displayed lowercase and grouped by spaces
+    /// let secret = "gezd gnbv gy3t qojq gezd gnbv gy3t qojq";
+    /// let time = Utc.timestamp_opt(1111111109, 0).unwrap();
+    /// assert_eq!(TwoFactor::totp_code(secret, time).unwrap(), "081804");
+    /// ```
+    pub fn totp_code(secret: &str, time: DateTime<Utc>) -> Result<String> {
+        // Keep the normalized secret and the key out of the freed memory
+        let secret = Zeroizing::new(
+            secret
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != '=')
+                .flat_map(char::to_uppercase)
+                .collect::<String>(),
+        );
+        let key = match BASE32_NOPAD.decode(secret.as_bytes()) {
+            Ok(key) => Zeroizing::new(key),
+            Err(e) => {
+                let message = format!("Invalid TOTP secret: {}", e);
+                error!("{}", message);
+                return Err(Error::msg(message));
+            }
+        };
+
+        let counter = time.timestamp().div_euclid(TOTP_PERIOD) as u64;
+        let mut mac = Hmac::<Sha1>::new_from_slice(&key)?;
+        mac.update(&counter.to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+
+        // Dynamic truncation of the hash
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+
+        let code = binary % 10u32.pow(TOTP_DIGITS);
+        Ok(format!("{:0width$}", code, width = TOTP_DIGITS as usize))
+    }
+}
@@ -0,0 +1,62 @@
+#![deny(missing_docs)]
+
+use crate::event::Event;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Get the unique and stable identifier of an event, to be used by every
+/// export and synchronization backend.
+///
+/// The `scope` identifies where the event comes from, for example the
+/// planning or the account it was fetched from, so that the same Aurion
+/// event exported from two different plannings does not collide.
+///
+/// The UID is of the form `{id}-{hash}@aurion_rs`, where `id` is the Aurion
+/// id of the event and `hash` is the 64-bit FNV-1a hash of the scope written
+/// as 16 lowercase hexadecimal digits. It only depends on the event id and the
+/// scope: an event whose date, room or title changes keeps the same UID, so
+/// re-synchronizing updates it instead of creating a duplicate.
+///
+/// This algorithm is part of the public contract of the crate and must not
+/// change, otherwise calendars synchronized with a previous version would
+/// end up with duplicated entries.
+///
+/// # Example
+///
+/// ```rust
+/// use aurion_rs::event::{Event, EventKind};
+/// use aurion_rs::uid::event_uid;
+/// use chrono::{TimeZone, Utc};
+///
+/// let event = Event {
+///     id: 42.into(),
+///     kind: EventKind::Course,
+///     start: Utc.with_ymd_and_hms(2023, 3, 6, 8, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2023, 3, 6, 10, 0, 0).unwrap(),
+///     all_day: false,
+///     editable: false,
+///     rooms: vec!["B101".into()],
+///     is_remote: false,
+///     subject: "Mathematics".to_string(),
+///     chapter: None,
+///     participants: vec![],
+///     group_id: None,
+///     diagnostic: None,
+///     raw_title: String::new(),
+///     raw_class_name: String::new(),
+/// };
+///
+/// assert_eq!(event_uid(&event, "user:1_3"), "42-5ea3a0232928551d@aurion_rs");
+/// ```
+pub fn event_uid<S: AsRef<str>>(event: &Event, scope: S) -> String {
+    format!("{}-{:016x}@aurion_rs", event.id, fnv1a(scope.as_ref()))
+}
+
+/// Hash the given string with the 64-bit FNV-1a algorithm, which unlike the
+/// standard library hashers is guaranteed to be stable across versions.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
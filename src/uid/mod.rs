@@ -0,0 +1,3 @@
+mod event_uid;
+
+pub use event_uid::event_uid;
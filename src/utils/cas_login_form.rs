@@ -0,0 +1,39 @@
+use super::Html;
+
+/// The login form of a CAS server.
+pub struct CasLoginForm {
+    /// The action of the form, relative to the login page.
+    pub action: String,
+
+    /// The hidden fields of the form, such as the login ticket or the
+    /// execution key, to submit along with the credentials.
+    pub fields: Vec<(String, String)>,
+}
+
+/// Parse the login form of a CAS server login page from an html content.
+/// The login form is the form holding the password input.
+pub fn parse_cas_login_form<T: Into<String>>(text: T) -> Option<CasLoginForm> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let form = html
+        .xpath("//form[.//input[@name='password']]")
+        .into_iter()
+        .next()?;
+
+    let fields = form
+        .findnodes(".//input[@type='hidden'][@name]")
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|input| {
+            let name = input.get_attribute("name")?;
+            let value = input.get_attribute("value").unwrap_or_default();
+            Some((name, value))
+        })
+        .collect();
+
+    Some(CasLoginForm {
+        action: form.get_attribute("action").unwrap_or_default(),
+        fields,
+    })
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// Get the UTC instant of the start of the given day in the given timezone.
+pub fn start_of_day(date: NaiveDate, timezone: Tz) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+    timezone
+        .from_local_datetime(&midnight)
+        .earliest()
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+/// Get the UTC boundaries of the given day in the given timezone.
+pub fn day_range(date: NaiveDate, timezone: Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let next_day = date.checked_add_days(Days::new(1)).unwrap();
+    (
+        start_of_day(date, timezone),
+        start_of_day(next_day, timezone),
+    )
+}
+
+/// Get the UTC boundaries of the week (from Monday to Sunday) containing the
+/// given day in the given timezone.
+pub fn week_range(date: NaiveDate, timezone: Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let monday = date.week(Weekday::Mon).first_day();
+    let next_monday = monday.checked_add_days(Days::new(7)).unwrap();
+    (
+        start_of_day(monday, timezone),
+        start_of_day(next_monday, timezone),
+    )
+}
+
+/// Get the day the given number of weeks after the given day, or before it
+/// if negative.
+pub fn shift_weeks(date: NaiveDate, weeks: i64) -> Option<NaiveDate> {
+    let days = Days::new(weeks.unsigned_abs() * 7);
+    match weeks >= 0 {
+        true => date.checked_add_days(days),
+        false => date.checked_sub_days(days),
+    }
+}
+
+/// Get the current day in the given timezone.
+pub fn today(timezone: Tz) -> NaiveDate {
+    Utc::now().with_timezone(&timezone).date_naive()
+}
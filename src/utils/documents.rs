@@ -0,0 +1,45 @@
+use super::Html;
+
+/// A row of a documents table.
+pub struct DocumentRow {
+    /// The id of the document.
+    pub id: String,
+
+    /// The name of the document, in the first non-empty column of the row.
+    pub name: String,
+
+    /// The id of the link or button downloading the document.
+    pub button_id: Option<String>,
+}
+
+/// Parse the rows of the documents table from an html content.
+pub fn parse_documents<T: Into<String>>(text: T) -> Vec<DocumentRow> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let mut documents = Vec::new();
+    for row in html.xpath("//tbody/tr[@data-rk]") {
+        let id = row.get_attribute("data-rk").unwrap_or_default();
+        let name = row
+            .get_child_elements()
+            .iter()
+            .map(|cell| cell.get_content().trim().to_string())
+            .find(|cell| !cell.is_empty())
+            .unwrap_or_default();
+        let button_id = row
+            .findnodes(".//*[self::a or self::button][@id]")
+            .ok()
+            .and_then(|buttons| {
+                buttons
+                    .first()
+                    .and_then(|button| button.get_attribute("id"))
+            });
+        documents.push(DocumentRow {
+            id,
+            name,
+            button_id,
+        });
+    }
+
+    documents
+}
@@ -0,0 +1,87 @@
+use tracing::{debug, error};
+
+use super::Html;
+use crate::event::{EventDetails, EventId};
+
+/// Parse the event details from the response to the event selection ajax
+/// request.
+///
+/// The detail view is made of fieldsets: the ones holding a table list the
+/// staff, the groups or the rooms of the event depending on their legend,
+/// and the other ones hold labelled fields.
+pub fn parse_event_details<T: AsRef<str>>(event_id: &EventId, text: T) -> Option<EventDetails> {
+    let text = text.as_ref();
+    let splitter = "<update id=\"form:modaleDetail\"><![CDATA[";
+    let splitted = text.split_once(splitter);
+    if splitted.is_none() {
+        error!("Failed to get event details.");
+        return None;
+    }
+    let content = splitted.unwrap().1.split_once("]]></update>")?.0;
+
+    // Parse the html content to support XPath
+    let html = Html::parse(content);
+
+    let mut details = EventDetails {
+        id: event_id.clone(),
+        ..EventDetails::default()
+    };
+
+    for fieldset in html.xpath("//fieldset") {
+        let legend = fieldset
+            .findnodes("./legend")
+            .ok()
+            .and_then(|legends| legends.first().map(|legend| legend.get_content()))
+            .unwrap_or_default()
+            .to_lowercase();
+
+        // Tables list the staff, the groups or the rooms of the event
+        let rows = fieldset.findnodes(".//tbody/tr").unwrap_or_default();
+        let list = if legend.contains("intervenant") || legend.contains("staff") {
+            Some(&mut details.staff)
+        } else if legend.contains("groupe") || legend.contains("group") {
+            Some(&mut details.groups)
+        } else if legend.contains("ressource")
+            || legend.contains("salle")
+            || legend.contains("room")
+        {
+            Some(&mut details.rooms)
+        } else {
+            None
+        };
+        if let Some(list) = list {
+            for row in rows {
+                let cells = row
+                    .get_child_elements()
+                    .iter()
+                    .map(|cell| cell.get_content().trim().to_string())
+                    .filter(|cell| !cell.is_empty())
+                    .collect::<Vec<String>>();
+                if !cells.is_empty() {
+                    list.push(cells.join(" "));
+                }
+            }
+            continue;
+        }
+
+        // Other fieldsets hold labels followed by their value
+        for label in fieldset.findnodes(".//label").unwrap_or_default() {
+            let name = label.get_content().trim().to_lowercase();
+            let value = label
+                .get_next_element_sibling()
+                .map(|value| value.get_content().trim().to_string())
+                .filter(|value| !value.is_empty());
+
+            if name.starts_with("type") {
+                details.kind_label = value;
+            } else if name.starts_with("matière") || name.starts_with("subject") {
+                details.subject = value;
+            } else if name.starts_with("description") || name.starts_with("commentaire") {
+                details.description = value;
+            }
+        }
+    }
+
+    debug!("Event details: {:?}", details);
+    Some(details)
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use super::parse_table;
+use crate::event::{EventId, Exam};
+
+/// Parse the exams table from an html content.
+///
+/// The dates of the table are written in the given school timezone.
+pub fn parse_exams<T: Into<String>>(text: T, timezone: Tz) -> Vec<Exam> {
+    parse_table(text)
+        .into_iter()
+        .map(|row| Exam {
+            event_id: row.id.parse::<u32>().ok().map(EventId::from),
+            subject: row
+                .get(&["matière", "épreuve", "subject", "libellé"])
+                .unwrap_or_default(),
+            start: row
+                .get(&["date", "début", "start"])
+                .and_then(|date| parse_date_time(&date, timezone)),
+            duration: row
+                .get(&["durée", "duration"])
+                .and_then(|duration| parse_duration(&duration)),
+            coefficient: row
+                .get(&["coef"])
+                .and_then(|coefficient| coefficient.replace(',', ".").parse().ok()),
+            authorized_materials: row.get(&["autorisé", "documents", "matériel", "material"]),
+            rooms: row
+                .get(&["salle", "room"])
+                .map(|rooms| {
+                    rooms
+                        .split(',')
+                        .map(|room| room.trim().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            id: row.id,
+        })
+        .collect()
+}
+
+/// Parse a date and time of the form "dd/mm/yyyy hh:mm" in the given
+/// timezone.
+fn parse_date_time(text: &str, timezone: Tz) -> Option<DateTime<Utc>> {
+    let date_time = NaiveDateTime::parse_from_str(text, "%d/%m/%Y %H:%M").ok()?;
+    let date_time = timezone.from_local_datetime(&date_time).earliest()?;
+    Some(date_time.with_timezone(&Utc))
+}
+
+/// Parse a duration of the form "2h00", "2h" or "120 min", in minutes.
+fn parse_duration(text: &str) -> Option<u32> {
+    let text = text.to_lowercase().replace(' ', "");
+    if let Some((hours, minutes)) = text.split_once('h') {
+        let minutes = match minutes.trim_end_matches("min") {
+            "" => 0,
+            minutes => minutes.parse().ok()?,
+        };
+        return Some(hours.parse::<u32>().ok()? * 60 + minutes);
+    }
+
+    text.trim_end_matches("min").parse().ok()
+}
@@ -1,32 +1,140 @@
-use log::{debug, error};
+use std::sync::OnceLock;
+
+use anyhow::{Error, Result};
+use regex::Regex;
+use tracing::{debug, error};
+
+use super::Html;
+
+/// The name of the script function loading the sub menus.
+const MENU_LOADER: &str = "chargerSousMenu";
+
+/// The functions wrapping a PrimeFaces ajax call, whatever their formatting,
+/// compiled once as the form id is parsed on every menu navigation.
+static PRIMEFACES_CALLS: OnceLock<Regex> = OnceLock::new();
 
 /// Get the form id from an html content.
 /// The form id is used to send ajax requests to get the menu.
-pub fn get_form_id<T: AsRef<str>>(text: T) -> Option<u8> {
+///
+/// The form id is the source of the `PrimeFaces.ab` call made by the
+/// `chargerSousMenu` function of the page scripts.
+pub fn get_form_id<T: AsRef<str>>(text: T) -> Result<u8> {
     let text = text.as_ref();
-    let splitter = "chargerSousMenu = function() {PrimeFaces.ab({s:\"form:j_idt";
-    let splitted = text.split(splitter).collect::<Vec<&str>>();
-    if splitted.len() < 2 {
-        error!("Failed to get form id.");
-        return None;
+
+    let calls = PRIMEFACES_CALLS.get_or_init(|| {
+        Regex::new(
+            r#"(\w+)\s*=\s*function\s*\(\s*\)\s*\{\s*PrimeFaces\.ab\(\s*\{\s*s\s*:\s*["']([^"']*)["']"#,
+        )
+        .expect("PrimeFaces call regex is valid")
+    });
+
+    let mut found = Vec::new();
+    for captures in calls.captures_iter(text) {
+        let (name, source) = (&captures[1], &captures[2]);
+        if name != MENU_LOADER {
+            found.push(format!("{} ({})", name, source));
+            continue;
+        }
+
+        return match parse_form_id(source) {
+            Some(form_id) => {
+                debug!("Form id: {}", form_id);
+                Ok(form_id)
+            }
+            None => {
+                let message = format!(
+                    "Failed to get form id: unexpected {} source {:?}",
+                    MENU_LOADER, source
+                );
+                error!("{}", message);
+                Err(Error::msg(message))
+            }
+        };
     }
-    let form_id = splitted[1].split("\"").collect::<Vec<&str>>()[0];
-    debug!("Form id: {}", form_id);
-    Some(form_id.parse().unwrap())
+
+    let message = match found.is_empty() {
+        true => format!(
+            "Failed to get form id: no PrimeFaces.ab call found in the page ({} bytes)",
+            text.len()
+        ),
+        false => format!(
+            "Failed to get form id: {} not found, found {}",
+            MENU_LOADER,
+            found.join(", ")
+        ),
+    };
+    error!("{}", message);
+    Err(Error::msg(message))
 }
 
 /// Get the schedule form id from an html content.
 /// The schedule form id is used to send ajax requests to get the schedule.
-pub fn get_schedule_form_id<T: AsRef<str>>(text: T) -> Option<u8> {
+///
+/// The schedule form id is the id of the element with the `schedule` class.
+pub fn get_schedule_form_id<T: Into<String>>(text: T) -> Result<u8> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let ids = html
+        .xpath("//*[contains(concat(' ', normalize-space(@class), ' '), ' schedule ')]")
+        .into_iter()
+        .map(|node| node.get_attribute("id"))
+        .collect::<Vec<Option<String>>>();
+
+    if let Some(form_id) = ids.iter().flatten().find_map(|id| parse_form_id(id)) {
+        debug!("Schedule form id: {}", form_id);
+        return Ok(form_id);
+    }
+
+    let message = match ids.is_empty() {
+        true => {
+            "Failed to get schedule form id: no element with the schedule class found".to_string()
+        }
+        false => format!(
+            "Failed to get schedule form id: unexpected schedule element ids {:?}",
+            ids
+        ),
+    };
+    error!("{}", message);
+    Err(Error::msg(message))
+}
+
+/// Parse the number of a form id of the form "form:j_idt42".
+fn parse_form_id(id: &str) -> Option<u8> {
+    id.strip_prefix("form:j_idt")?.parse().ok()
+}
+
+/// Get the planning choice form id from an html content.
+/// The planning choice form id is the id of the button submitting the
+/// selected planning in the planning choice page.
+pub fn get_planning_choice_form_id<T: AsRef<str>>(text: T) -> Option<u16> {
     let text = text.as_ref();
-    let splitter = "\" class=\"schedule\"";
+    let splitter = "<button id=\"form:j_idt";
     let splitted = text.split_once(splitter);
     if splitted.is_none() {
-        error!("Failed to get schedule form id.");
+        error!("Failed to get planning choice form id.");
         return None;
     }
-    let splitted = splitted.unwrap();
-    let schedule_form_id = splitted.0.rsplit_once("id=\"form:j_idt").unwrap().1;
-    debug!("Schedule form id: {}", schedule_form_id);
-    Some(schedule_form_id.parse().unwrap())
+    let form_id = splitted.unwrap().1.split('"').next()?;
+    debug!("Planning choice form id: {}", form_id);
+    form_id.parse().ok()
+}
+
+/// Get the id of the button whose text contains one of the given labels,
+/// ignoring case, from an html content.
+pub fn get_button_form_id<T: AsRef<str>>(text: T, labels: &[&str]) -> Option<u16> {
+    let text = text.as_ref();
+    for button in text.split("<button id=\"form:j_idt").skip(1) {
+        let (form_id, content) = button.split_once('"')?;
+        let content = content.split("</button>").next()?.to_lowercase();
+        if labels
+            .iter()
+            .any(|label| content.contains(&label.to_lowercase()))
+        {
+            debug!("Button form id: {}", form_id);
+            return form_id.parse().ok();
+        }
+    }
+    error!("Failed to get button form id.");
+    None
 }
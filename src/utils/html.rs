@@ -0,0 +1,42 @@
+use libxml::parser::Parser;
+use libxml::tree::{Document, Node};
+use libxml::xpath::Context;
+
+/// An html document that can be queried with XPath expressions.
+///
+/// The nodes returned by [`Html::xpath`] only hold a weak reference to the
+/// document, so the `Html` must outlive them.
+pub struct Html {
+    context: Option<(Document, Context)>,
+}
+
+impl Html {
+    /// Parse an html content. Empty or unparsable contents give a document
+    /// without any node.
+    pub fn parse<T: Into<String>>(text: T) -> Self {
+        let text = text.into();
+        if text.trim().is_empty() {
+            return Self { context: None };
+        }
+
+        let context = Parser::default_html()
+            .parse_string(&text)
+            .ok()
+            .and_then(|document| {
+                let context = Context::new(&document).ok()?;
+                Some((document, context))
+            });
+        Self { context }
+    }
+
+    /// Get the nodes matching the given XPath expression.
+    pub fn xpath(&self, expression: &str) -> Vec<Node> {
+        match &self.context {
+            Some((_, context)) => context
+                .evaluate(expression)
+                .map(|object| object.get_nodes_as_vec())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+}
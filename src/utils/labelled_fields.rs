@@ -0,0 +1,39 @@
+use super::Html;
+
+/// Parse the labelled fields of an html content, either a label followed by
+/// its value or a table row whose first cell is the label and the second one
+/// the value.
+///
+/// The labels are lowercased and stripped of their trailing colon, and the
+/// whitespaces of the values are collapsed. Fields without a value are
+/// skipped.
+pub fn parse_labelled_fields<T: Into<String>>(text: T) -> Vec<(String, String)> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let mut fields = Vec::new();
+    for label in html.xpath("//label") {
+        let value = label
+            .get_next_element_sibling()
+            .map(|value| value.get_content());
+        fields.push((label.get_content(), value));
+    }
+    for row in html.xpath("//tr[count(td) = 2]") {
+        let cells = row.findnodes("./td").unwrap_or_default();
+        if let [name, value] = cells.as_slice() {
+            fields.push((name.get_content(), Some(value.get_content())));
+        }
+    }
+
+    fields
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let name = name.trim().trim_end_matches(':').trim().to_lowercase();
+            let value = value?.split_whitespace().collect::<Vec<&str>>().join(" ");
+            match value.is_empty() {
+                true => None,
+                false => Some((name, value)),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,94 @@
+use std::sync::OnceLock;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use regex::Regex;
+use tracing::debug;
+
+use super::Html;
+
+/// The dates and times of the maintenance page, such as "12/03/2024 à 14h00"
+/// or "14:00", compiled once.
+static DATE_TIMES: OnceLock<Regex> = OnceLock::new();
+
+/// Check whether an html content is the maintenance page served by Aurion
+/// during maintenance windows.
+/// The page is recognized by the title or the headings mentioning the
+/// maintenance, so that the events or fields of a regular page mentioning it
+/// are not mistaken for it.
+pub fn is_maintenance_page<T: AsRef<str>>(text: T) -> bool {
+    let text = text.as_ref();
+    if !text.to_lowercase().contains("maintenance") {
+        return false;
+    }
+
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+    html.xpath("//title | //h1 | //h2 | //h3")
+        .iter()
+        .any(|node| node.get_content().to_lowercase().contains("maintenance"))
+}
+
+/// Get the announced end of the maintenance from the maintenance page.
+///
+/// The end is the first date and time following "jusqu'" or "until", such as
+/// "jusqu'au 12/03/2024 à 14h00", or else the last one of the page, such as
+/// the end of "de 12h00 à 14h00". Times without a date are taken on the given
+/// day, and all of them are expressed in the given timezone.
+pub fn get_maintenance_end<T: Into<String>>(
+    text: T,
+    timezone: Tz,
+    today: NaiveDate,
+) -> Option<DateTime<Utc>> {
+    // Parse the html content to only read its text
+    let html = Html::parse(text);
+    let content = html
+        .xpath("//body")
+        .first()
+        .map(|body| body.get_content())?
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let dates = DATE_TIMES.get_or_init(|| {
+        Regex::new(r"(?:(\d{1,2})/(\d{1,2})/(\d{4})\D{1,10}?)?\b(\d{1,2})\s*[h:]\s*(\d{2})?")
+            .expect("Date time regex is valid")
+    });
+
+    let lowercase = content.to_lowercase();
+    let marker = ["jusqu", "until"]
+        .iter()
+        .filter_map(|marker| lowercase.find(marker))
+        .min();
+
+    let mut candidates = dates.captures_iter(&content).filter_map(|captures| {
+        let date = match (captures.get(1), captures.get(2), captures.get(3)) {
+            (Some(day), Some(month), Some(year)) => NaiveDate::from_ymd_opt(
+                year.as_str().parse().ok()?,
+                month.as_str().parse().ok()?,
+                day.as_str().parse().ok()?,
+            )?,
+            _ => today,
+        };
+        let hours = captures[4].parse().ok()?;
+        let minutes = match captures.get(5) {
+            Some(minutes) => minutes.as_str().parse().ok()?,
+            None => 0,
+        };
+        let time = NaiveTime::from_hms_opt(hours, minutes, 0)?;
+        let end = date
+            .and_time(time)
+            .and_local_timezone(timezone)
+            .earliest()?
+            .with_timezone(&Utc);
+        Some((captures.get(0)?.start(), end))
+    });
+
+    let end = match marker {
+        Some(marker) => candidates.find(|(position, _)| *position > marker),
+        None => candidates.last(),
+    }
+    .map(|(_, end)| end);
+    debug!("Maintenance end: {:?}", end);
+    end
+}
@@ -0,0 +1,24 @@
+use super::Html;
+
+/// Parse the parent nodes of the sidebar menu from an html content, returned
+/// as their `submenu_*` id and their label.
+pub fn parse_menu_roots<T: Into<String>>(text: T) -> Vec<(String, String)> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let mut roots = Vec::new();
+    for node in html.xpath("//li[contains(@class, \"ui-menu-parent\")]") {
+        let class = node.get_attribute("class").unwrap_or_default();
+        let id = match class.split_once(" submenu_") {
+            Some((_, id)) => format!("submenu_{}", id.split(' ').next().unwrap_or_default()),
+            None => continue,
+        };
+        let name = match node.findnodes("a/span[@class=\"ui-menuitem-text\"]/text()") {
+            Ok(names) if !names.is_empty() => names[0].get_content().trim().to_string(),
+            _ => continue,
+        };
+        roots.push((id, name));
+    }
+
+    roots
+}
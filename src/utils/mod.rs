@@ -1,6 +1,46 @@
+mod cas_login_form;
+mod date_range;
+mod documents;
+mod event_details;
+mod exams;
 mod form_id;
+mod html;
+mod labelled_fields;
+mod maintenance;
+mod menu_roots;
+mod planning_choice;
+mod profile;
+mod roster;
+mod stages;
+mod table;
+mod two_factor_form;
 mod view_state;
 
+pub use cas_login_form::parse_cas_login_form;
+pub use date_range::day_range;
+pub use date_range::shift_weeks;
+pub use date_range::today;
+pub use date_range::week_range;
+pub use documents::parse_documents;
+pub use event_details::parse_event_details;
+pub use exams::parse_exams;
+pub use form_id::get_button_form_id;
 pub use form_id::get_form_id;
+pub use form_id::get_planning_choice_form_id;
 pub use form_id::get_schedule_form_id;
+pub use html::Html;
+pub use labelled_fields::parse_labelled_fields;
+pub use maintenance::get_maintenance_end;
+pub use maintenance::is_maintenance_page;
+pub use menu_roots::parse_menu_roots;
+pub use planning_choice::parse_planning_choices;
+pub use profile::parse_profile;
+pub use roster::parse_roster;
+pub use stages::parse_internship_offer;
+pub use stages::parse_internship_offer_details;
+pub use stages::parse_internship_offers;
+pub use stages::parse_internships;
+pub use table::parse_table;
+pub use two_factor_form::parse_two_factor_form;
 pub use view_state::get_view_state;
+pub use view_state::is_view_expired;
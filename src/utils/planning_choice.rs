@@ -0,0 +1,61 @@
+use super::Html;
+
+/// A row of the planning choice table.
+pub struct PlanningChoice {
+    /// The id of the planning.
+    pub id: u32,
+
+    /// The name of the planning, in the last column of the row.
+    pub name: String,
+
+    /// The text of the non-empty columns of the row.
+    pub columns: Vec<String>,
+
+    /// Whether the planning is a favorite, flagged by a filled star icon.
+    pub favorite: bool,
+}
+
+/// Parse the rows of the planning choice table from an html content.
+/// Each row is a planning (class group, room, teacher, etc.) that can be
+/// selected.
+pub fn parse_planning_choices<T: Into<String>>(text: T) -> Vec<PlanningChoice> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let rows = html.xpath("//div[@id=\"form:dataTableFavori\"]//tbody/tr");
+
+    let mut choices = Vec::new();
+    for row in rows {
+        let id = row
+            .get_attribute("data-rk")
+            .unwrap()
+            .parse::<u32>()
+            .unwrap();
+        let name = row
+            .get_last_element_child()
+            .unwrap()
+            .get_last_element_child()
+            .unwrap()
+            .get_content();
+        let columns = row
+            .get_child_elements()
+            .iter()
+            .map(|cell| cell.get_content().trim().to_string())
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        let favorite = row
+            .findnodes(
+                ".//*[contains(@class, \"fa-star\") and not(contains(@class, \"fa-star-o\"))]",
+            )
+            .map(|icons| !icons.is_empty())
+            .unwrap_or(false);
+        choices.push(PlanningChoice {
+            id,
+            name,
+            columns,
+            favorite,
+        });
+    }
+
+    choices
+}
@@ -0,0 +1,42 @@
+use tracing::debug;
+
+use super::parse_labelled_fields;
+use crate::profile::Profile;
+
+/// Parse the profile of the user from the html content of the personal
+/// information page.
+///
+/// Returns `None` if no name could be found.
+pub fn parse_profile<T: Into<String>>(text: T) -> Option<Profile> {
+    let mut profile = Profile::default();
+    let mut first_name = None;
+    let mut last_name = None;
+    for (name, value) in parse_labelled_fields(text) {
+        if name.starts_with("prénom") || name.starts_with("first name") {
+            first_name.get_or_insert(value);
+        } else if name.starts_with("nom") || name.starts_with("last name") || name == "name" {
+            last_name.get_or_insert(value);
+        } else if name.contains("matricule")
+            || name.contains("numéro étudiant")
+            || name.contains("student number")
+            || name.contains("student id")
+        {
+            profile.student_number.get_or_insert(value);
+        } else if name.starts_with("promotion") || name.starts_with("classe") {
+            profile.promotion.get_or_insert(value);
+        } else if name.contains("mail") {
+            profile.email.get_or_insert(value);
+        } else if name.starts_with("adresse") || name.starts_with("address") {
+            profile.address.get_or_insert(value);
+        }
+    }
+
+    profile.name = match (first_name, last_name) {
+        (Some(first_name), Some(last_name)) => format!("{} {}", first_name, last_name),
+        (Some(name), None) | (None, Some(name)) => name,
+        (None, None) => return None,
+    };
+
+    debug!("Profile: {:?}", profile);
+    Some(profile)
+}
@@ -0,0 +1,37 @@
+use super::Html;
+
+/// A cell of the trombinoscope grid.
+pub struct RosterCell {
+    /// The name of the student, the text of the cell.
+    pub name: String,
+
+    /// The source of the photo of the student, as written in the page.
+    pub photo_src: Option<String>,
+}
+
+/// Parse the cells of the trombinoscope grid from an html content.
+/// Each cell holds the photo and the name of a student.
+pub fn parse_roster<T: Into<String>>(text: T) -> Vec<RosterCell> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let mut cells = Vec::new();
+    for cell in html.xpath("//*[contains(@class, 'ui-datagrid-column')]") {
+        let name = cell
+            .get_content()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        if name.is_empty() {
+            continue;
+        }
+
+        let photo_src = cell
+            .findnodes(".//img[@src]")
+            .ok()
+            .and_then(|images| images.first().and_then(|image| image.get_attribute("src")));
+        cells.push(RosterCell { name, photo_src });
+    }
+
+    cells
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+
+use super::table::TableRow;
+use super::{parse_labelled_fields, parse_table};
+use crate::stages::{Internship, InternshipOffer};
+
+const TITLE_LABELS: [&str; 3] = ["intitulé", "titre", "title"];
+const SUBJECT_LABELS: [&str; 2] = ["sujet", "subject"];
+const COMPANY_LABELS: [&str; 4] = ["entreprise", "société", "organisme", "company"];
+const LOCATION_LABELS: [&str; 4] = ["lieu", "ville", "location", "city"];
+const TUTOR_LABELS: [&str; 2] = ["tuteur", "tutor"];
+const START_LABELS: [&str; 2] = ["début", "start"];
+const END_LABELS: [&str; 2] = ["fin", "end"];
+const STATUS_LABELS: [&str; 3] = ["statut", "état", "status"];
+const DESCRIPTION_LABELS: [&str; 2] = ["description", "descriptif"];
+const CONTACT_LABELS: [&str; 1] = ["contact"];
+
+/// Parse the internship offers table from an html content.
+pub fn parse_internship_offers<T: Into<String>>(text: T) -> Vec<InternshipOffer> {
+    parse_table(text)
+        .iter()
+        .map(parse_internship_offer)
+        .collect()
+}
+
+/// Parse an internship offer from a row of the offers table.
+pub fn parse_internship_offer(row: &TableRow) -> InternshipOffer {
+    InternshipOffer {
+        id: row.id.clone(),
+        title: row.get(&TITLE_LABELS).unwrap_or_default(),
+        company: row.get(&COMPANY_LABELS),
+        location: row.get(&LOCATION_LABELS),
+        start: row.get(&START_LABELS).and_then(parse_date),
+        end: row.get(&END_LABELS).and_then(parse_date),
+        ..InternshipOffer::default()
+    }
+}
+
+/// Complete an internship offer with the fields of its detail page.
+pub fn parse_internship_offer_details<T: Into<String>>(offer: &mut InternshipOffer, text: T) {
+    for (name, value) in parse_labelled_fields(text) {
+        let is = |labels: &[&str]| labels.iter().any(|label| name.starts_with(label));
+        if is(&DESCRIPTION_LABELS) {
+            offer.description.get_or_insert(value);
+        } else if is(&CONTACT_LABELS) {
+            offer.contact.get_or_insert(value);
+        } else if is(&COMPANY_LABELS) {
+            offer.company.get_or_insert(value);
+        } else if is(&LOCATION_LABELS) {
+            offer.location.get_or_insert(value);
+        } else if is(&START_LABELS) {
+            offer.start = offer.start.or(parse_date(value));
+        } else if is(&END_LABELS) {
+            offer.end = offer.end.or(parse_date(value));
+        } else if is(&TITLE_LABELS) && offer.title.is_empty() {
+            offer.title = value;
+        }
+    }
+}
+
+/// Parse the internship record table from an html content.
+pub fn parse_internships<T: Into<String>>(text: T) -> Vec<Internship> {
+    parse_table(text)
+        .into_iter()
+        .map(|row| Internship {
+            subject: row.get(&SUBJECT_LABELS).or(row.get(&TITLE_LABELS)),
+            company: row.get(&COMPANY_LABELS),
+            tutor: row.get(&TUTOR_LABELS),
+            start: row.get(&START_LABELS).and_then(parse_date),
+            end: row.get(&END_LABELS).and_then(parse_date),
+            status: row.get(&STATUS_LABELS),
+            id: row.id,
+        })
+        .collect()
+}
+
+/// Parse a date of the form "dd/mm/yyyy", possibly followed by a time.
+fn parse_date(text: String) -> Option<NaiveDate> {
+    let date = text.split_whitespace().next()?;
+    NaiveDate::parse_from_str(date, "%d/%m/%Y").ok()
+}
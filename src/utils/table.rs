@@ -0,0 +1,80 @@
+use super::Html;
+
+/// A row of a data table, whose cells are labelled by the table headers.
+pub struct TableRow {
+    /// The id of the row.
+    pub id: String,
+
+    /// The lowercase header and the text of every cell of the row.
+    pub cells: Vec<(String, String)>,
+
+    /// The id of the first link or button of the row.
+    pub button_id: Option<String>,
+}
+
+impl TableRow {
+    /// Get the text of the first non-empty cell whose header contains one of
+    /// the given labels.
+    pub fn get(&self, labels: &[&str]) -> Option<String> {
+        self.cells
+            .iter()
+            .find(|(header, value)| {
+                !value.is_empty() && labels.iter().any(|label| header.contains(label))
+            })
+            .map(|(_, value)| value.clone())
+    }
+}
+
+/// Parse the rows of the first data table from an html content.
+pub fn parse_table<T: Into<String>>(text: T) -> Vec<TableRow> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let tables = html.xpath("//table[tbody/tr[@data-rk]]");
+    let table = match tables.first() {
+        Some(table) => table,
+        None => return Vec::new(),
+    };
+
+    let headers = table
+        .findnodes("./thead/tr/th")
+        .unwrap_or_default()
+        .iter()
+        .map(|header| normalize(&header.get_content()).to_lowercase())
+        .collect::<Vec<String>>();
+
+    let mut rows = Vec::new();
+    for row in table.findnodes("./tbody/tr[@data-rk]").unwrap_or_default() {
+        let id = row.get_attribute("data-rk").unwrap_or_default();
+        let cells = row
+            .findnodes("./td")
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let header = headers.get(index).cloned().unwrap_or_default();
+                (header, normalize(&cell.get_content()))
+            })
+            .collect();
+        let button_id = row
+            .findnodes(".//*[self::a or self::button][@id]")
+            .ok()
+            .and_then(|buttons| {
+                buttons
+                    .first()
+                    .and_then(|button| button.get_attribute("id"))
+            });
+        rows.push(TableRow {
+            id,
+            cells,
+            button_id,
+        });
+    }
+
+    rows
+}
+
+/// Collapse the whitespaces of a text.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -0,0 +1,58 @@
+use super::Html;
+
+/// The form of a two-factor challenge page.
+pub struct TwoFactorForm {
+    /// The action of the form, relative to the challenge page.
+    pub action: String,
+
+    /// The name of the input of the one-time code.
+    pub code_field: String,
+
+    /// The hidden fields of the form, such as the view state, to submit
+    /// along with the code.
+    pub fields: Vec<(String, String)>,
+}
+
+/// Parse the form of a two-factor challenge from an html content.
+/// The challenge form is the form holding a visible input meant for a
+/// one-time code, either flagged with the `one-time-code` autocompletion or
+/// named after an OTP, a code or a token.
+pub fn parse_two_factor_form<T: Into<String>>(text: T) -> Option<TwoFactorForm> {
+    // Parse the html content to support XPath
+    let html = Html::parse(text);
+
+    let lowercase_name =
+        "translate(@name, 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz')";
+    let code_input = format!(
+        ".//input[@name][not(@type='hidden')][@autocomplete='one-time-code' \
+         or contains({name}, 'otp') or contains({name}, 'code') or contains({name}, 'token')]",
+        name = lowercase_name
+    );
+
+    let form = html
+        .xpath(&format!("//form[{}]", code_input))
+        .into_iter()
+        .next()?;
+    let code_field = form
+        .findnodes(&code_input)
+        .ok()?
+        .first()?
+        .get_attribute("name")?;
+
+    let fields = form
+        .findnodes(".//input[@type='hidden'][@name]")
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|input| {
+            let name = input.get_attribute("name")?;
+            let value = input.get_attribute("value").unwrap_or_default();
+            Some((name, value))
+        })
+        .collect();
+
+    Some(TwoFactorForm {
+        action: form.get_attribute("action").unwrap_or_default(),
+        code_field,
+        fields,
+    })
+}
@@ -1,4 +1,4 @@
-use log::{debug, error};
+use tracing::{debug, error};
 
 /// Get view state from an html content.
 /// The view state is used to send a request to the server.
@@ -16,3 +16,10 @@ pub fn get_view_state<T: AsRef<str>>(text: T) -> Option<String> {
     debug!("View state: {}", view_state);
     Some(view_state.to_string())
 }
+
+/// Check whether a response reports that the submitted view state expired.
+/// JSF answers with a `ViewExpiredException`, either as the error of a
+/// partial response or in an error page.
+pub fn is_view_expired<T: AsRef<str>>(text: T) -> bool {
+    text.as_ref().contains("ViewExpiredException")
+}
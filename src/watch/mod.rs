@@ -0,0 +1,4 @@
+mod webhook;
+
+pub use webhook::Webhook;
+pub use webhook::WebhookPayload;
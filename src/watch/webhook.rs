@@ -0,0 +1,72 @@
+#![deny(missing_docs)]
+
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error};
+
+use crate::schedule::{DateRange, ScheduleDiff};
+
+/// The JSON payload posted to a webhook when a watched schedule changed.
+///
+/// The fields of the [`ScheduleDiff`] are flattened into the payload, the
+/// `removed` events being the cancelled ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    /// When the change was detected.
+    pub detected_at: DateTime<Utc>,
+
+    /// The start of the watched date range.
+    pub start: DateTime<Utc>,
+
+    /// The end of the watched date range.
+    pub end: DateTime<Utc>,
+
+    /// The changes of the schedule.
+    #[serde(flatten)]
+    pub diff: ScheduleDiff,
+}
+
+/// A webhook notified of the changes of a watched schedule, see
+/// [`Aurion::watch_schedule`](crate::Aurion::watch_schedule).
+pub struct Webhook {
+    url: Url,
+    client: Client,
+}
+
+impl Webhook {
+    /// Create a webhook posting to the given url.
+    pub fn new<U: AsRef<str>>(url: U) -> Result<Self> {
+        Ok(Self {
+            url: Url::parse(url.as_ref())?,
+            client: Client::new(),
+        })
+    }
+
+    /// Post the changes of the schedule in the given range to the webhook.
+    pub async fn notify(&self, range: &DateRange, diff: ScheduleDiff) -> Result<()> {
+        let payload = WebhookPayload {
+            detected_at: Utc::now(),
+            start: range.start,
+            end: range.end,
+            diff,
+        };
+
+        debug!("Posting schedule changes to {}", self.url);
+        let response = self
+            .client
+            .post(self.url.clone())
+            .json(&payload)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let message = format!("Webhook responded with {}", response.status());
+            error!("{}", message);
+            return Err(Error::msg(message));
+        }
+
+        Ok(())
+    }
+}